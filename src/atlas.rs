@@ -0,0 +1,260 @@
+// Every numeral rasterized into one sprite sheet, with the metrics a text
+// renderer needs to place each cell. All glyphs share one scale and one
+// baseline, so sprites drawn at their bearings line up like `layout` does.
+use crate::geom::union;
+use crate::pick::tight_bounds;
+use crate::raster::{coverage, Bitmap};
+use crate::style::GlyphStyle;
+use crate::typeset::digit_span;
+use crate::{Numeral, Set, SortScheme};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AtlasOptions {
+    // Width and height of each cell in pixels, padding included
+    pub cell: [usize; 2],
+    // Blank pixels kept between the glyphs and their cell edges
+    pub padding: usize,
+    pub columns: usize,
+    // Cells run left to right, top to bottom in this order
+    pub ordering: SortScheme,
+    pub foreground: [u8; 4],
+    pub background: [u8; 4],
+    // Samples per pixel along each axis
+    pub samples: usize,
+}
+
+impl Default for AtlasOptions {
+    fn default() -> AtlasOptions {
+        AtlasOptions {
+            cell: [64, 64],
+            padding: 2,
+            columns: 12,
+            ordering: SortScheme::Value,
+            foreground: [255, 255, 255, 255],
+            background: [0, 0, 0, 0],
+            samples: 4,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct AtlasGlyph {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "value", serialize_with = "numeral_value")
+    )]
+    pub numeral: Numeral,
+    // x, y, width and height in pixels of the inked part of the cell
+    pub rect: [usize; 4],
+    // The rect as fractions of the sheet, u0, v0, u1, v1 from the top left
+    pub uv: [f32; 4],
+    // Pixels the pen moves past this glyph, before any layout spacing
+    pub advance: f32,
+    // Pixels from the pen position on the baseline to the rect's top left
+    // corner, x to the right and y up
+    pub bearing: [f32; 2],
+}
+
+// Serialized without the image, glyphs keyed by `Numeral::encoding` in sheet
+// order. `to_json` writes the same thing in any build; other formats such as
+// RON need the `serde` feature.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Atlas {
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub image: Bitmap,
+    pub width: usize,
+    pub height: usize,
+    pub cell: [usize; 2],
+    // Pixels per glyph unit
+    pub scale: f32,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "glyph_map"))]
+    pub glyphs: Vec<AtlasGlyph>,
+}
+
+#[cfg(feature = "serde")]
+fn numeral_value<S: Serializer>(u: &Numeral, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_u8(u.u())
+}
+
+#[cfg(feature = "serde")]
+pub(crate) fn glyph_map<S: Serializer>(glyphs: &[AtlasGlyph], s: S) -> Result<S::Ok, S::Error> {
+    s.collect_map(glyphs.iter().map(|g| (g.numeral.encoding(), g)))
+}
+
+// The glyphs as a JSON object in the shape `glyph_map` serializes
+pub(crate) fn glyphs_json(glyphs: &[AtlasGlyph]) -> String {
+    let entries: Vec<String> = glyphs
+        .iter()
+        .map(|g| {
+            let [x, y, w, h] = g.rect;
+            let [u0, v0, u1, v1] = g.uv;
+            format!(
+                concat!(
+                    "\"{}\":{{\"value\":{},\"rect\":[{},{},{},{}],",
+                    "\"uv\":[{:?},{:?},{:?},{:?}],\"advance\":{:?},\"bearing\":[{:?},{:?}]}}"
+                ),
+                g.numeral.encoding(),
+                g.numeral.u(),
+                x,
+                y,
+                w,
+                h,
+                u0,
+                v0,
+                u1,
+                v1,
+                g.advance,
+                g.bearing[0],
+                g.bearing[1]
+            )
+        })
+        .collect();
+    format!("{{{}}}", entries.join(","))
+}
+
+// Equal cells, `columns` to a row, shared with the SDF atlas
+pub(crate) struct Packer {
+    pub cell: [usize; 2],
+    pub columns: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Packer {
+    pub fn new(count: usize, cell: [usize; 2], columns: usize) -> Packer {
+        if columns == 0 {
+            panic!("An atlas needs at least one column!")
+        }
+        let rows = count.div_ceil(columns);
+        Packer {
+            cell,
+            columns,
+            width: columns * cell[0],
+            height: rows * cell[1],
+        }
+    }
+    // Pixel position of the top left corner of the i-th cell
+    pub fn cell(&self, i: usize) -> [usize; 2] {
+        [
+            (i % self.columns) * self.cell[0],
+            (i / self.columns) * self.cell[1],
+        ]
+    }
+    // Metrics of a rect on the sheet, given the pen on the baseline in sheet
+    // pixels and the advance in pixels
+    pub fn glyph(
+        &self,
+        numeral: Numeral,
+        rect: [usize; 4],
+        pen: [f32; 2],
+        advance: f32,
+    ) -> AtlasGlyph {
+        let (w, h) = (self.width as f32, self.height as f32);
+        AtlasGlyph {
+            numeral,
+            rect,
+            uv: [
+                rect[0] as f32 / w,
+                rect[1] as f32 / h,
+                (rect[0] + rect[2]) as f32 / w,
+                (rect[1] + rect[3]) as f32 / h,
+            ],
+            advance,
+            bearing: [rect[0] as f32 - pen[0], pen[1] - rect[1] as f32],
+        }
+    }
+}
+
+pub fn build_atlas(style: &GlyphStyle, opts: &AtlasOptions) -> Atlas {
+    let numerals = Set::new().sort_by_scheme(opts.ordering).values();
+    let packer = Packer::new(numerals.len(), opts.cell, opts.columns);
+    let [cw, ch] = opts.cell;
+    let (inner_w, inner_h) = (
+        cw.saturating_sub(2 * opts.padding),
+        ch.saturating_sub(2 * opts.padding),
+    );
+    if inner_w == 0 || inner_h == 0 {
+        panic!("Cell size leaves no room inside the padding!")
+    }
+    let meshes: Vec<_> = numerals.iter().map(|u| style.numeral_mesh(*u)).collect();
+    let tight: Vec<[f32; 4]> = meshes
+        .iter()
+        .map(|m| tight_bounds(m).unwrap_or_default())
+        .collect();
+    let [ux0, uy0, ux1, uy1] = union(tight.iter().copied()).unwrap_or_default();
+    // shaved so rounding out to whole pixels stays inside the padding
+    let scale = (inner_w as f32 / (ux1 - ux0)).min(inner_h as f32 / (uy1 - uy0)) * (1.0 - 1e-5);
+    // the union of all glyphs is centered in the cell, so every glyph keeps
+    // the same origin within its cell
+    let offset = [
+        opts.padding as f32 + (inner_w as f32 - (ux1 - ux0) * scale) / 2.0,
+        opts.padding as f32 + (inner_h as f32 - (uy1 - uy0) * scale) / 2.0,
+    ];
+    let to_cell = |x: f32, y: f32| [offset[0] + (x - ux0) * scale, offset[1] + (uy1 - y) * scale];
+    let origin = [ux0 - offset[0] / scale, uy1 + offset[1] / scale];
+    // `layout` puts the baseline half the glyph height below its origin
+    let baseline = -0.5 * style.height();
+    // the pen sits at the left of the digit span, as in `layout`
+    let [lx0, lx1] = digit_span(style);
+
+    let mut image = Bitmap::new(packer.width, packer.height, opts.background);
+    let mut glyphs = Vec::new();
+    for (i, u) in numerals.iter().enumerate() {
+        let [cx, cy] = packer.cell(i);
+        let cov = coverage(&meshes[i], cw, ch, origin, scale, opts.samples);
+        for (j, c) in cov.iter().enumerate() {
+            if *c > 0.0 {
+                image.blend(cx + j % cw, cy + j / cw, opts.foreground, *c);
+            }
+        }
+        let [x0, y0, x1, y1] = tight[i];
+        let [left, top] = to_cell(x0, y1);
+        let [right, bottom] = to_cell(x1, y0);
+        let (left, top) = (left.floor() as usize, top.floor() as usize);
+        let (right, bottom) = (
+            (right.ceil() as usize).min(cw),
+            (bottom.ceil() as usize).min(ch),
+        );
+        let pen = to_cell(lx0, baseline);
+        glyphs.push(packer.glyph(
+            *u,
+            [cx + left, cy + top, right - left, bottom - top],
+            [cx as f32 + pen[0], cy as f32 + pen[1]],
+            (lx1 - lx0) * scale,
+        ));
+    }
+    Atlas {
+        image,
+        width: packer.width,
+        height: packer.height,
+        cell: opts.cell,
+        scale,
+        glyphs,
+    }
+}
+
+impl Atlas {
+    pub fn get(&self, encoding: &str) -> Option<&AtlasGlyph> {
+        self.glyphs
+            .iter()
+            .find(|g| g.numeral.encoding() == encoding)
+    }
+    pub fn to_png(&self) -> Vec<u8> {
+        self.image.to_png()
+    }
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"width\":{},\"height\":{},\"cell\":[{},{}],\"scale\":{:?},\"glyphs\":{}}}",
+            self.width,
+            self.height,
+            self.cell[0],
+            self.cell[1],
+            self.scale,
+            glyphs_json(&self.glyphs)
+        )
+    }
+}
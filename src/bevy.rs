@@ -0,0 +1,96 @@
+// Builds Bevy meshes with positions, normals, UVs and vertex colors. Only the
+// mesh crates are pulled in, so construction works without a window or GPU.
+use crate::mesh::{Mesh, UvMapping};
+use crate::style::GlyphStyle;
+use crate::typeset::{layout, separator_mesh, LayoutOptions};
+use crate::{Numeral, Uint};
+use bevy_asset::RenderAssetUsages;
+use bevy_mesh::{Indices, PrimitiveTopology};
+
+pub use bevy_mesh::Mesh as BevyMesh;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BevyMeshOptions {
+    pub uv_mapping: UvMapping,
+    // sRGBA per diacritic tier, in the order of `COLORS`
+    pub tier_colors: [[u8; 4]; 5],
+    pub separator_color: [u8; 4],
+}
+
+impl Default for BevyMeshOptions {
+    fn default() -> BevyMeshOptions {
+        BevyMeshOptions {
+            uv_mapping: UvMapping::BoundingBox,
+            // the CSS colors named in `COLORS`
+            tier_colors: [
+                [255, 255, 0, 255],
+                [0, 0, 255, 255],
+                [255, 0, 0, 255],
+                [128, 0, 128, 255],
+                [0, 128, 0, 255],
+            ],
+            separator_color: [0, 0, 0, 255],
+        }
+    }
+}
+
+// Bevy expects vertex colors in linear space
+fn linear(c: [u8; 4]) -> [f32; 4] {
+    let f = |v: u8| {
+        let s = v as f32 / 255.0;
+        if s <= 0.04045 {
+            s / 12.92
+        } else {
+            ((s + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    [f(c[0]), f(c[1]), f(c[2]), c[3] as f32 / 255.0]
+}
+
+pub fn to_bevy_mesh(mesh: &Mesh, colors: &[[f32; 4]], uv_mapping: UvMapping) -> BevyMesh {
+    assert_eq!(
+        colors.len(),
+        mesh.vertices.len(),
+        "Need one color per vertex!"
+    );
+    let mesh = mesh.wind_ccw();
+    BevyMesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    )
+    .with_inserted_attribute(BevyMesh::ATTRIBUTE_POSITION, mesh.vertices.clone())
+    .with_inserted_attribute(BevyMesh::ATTRIBUTE_NORMAL, mesh.normals())
+    .with_inserted_attribute(BevyMesh::ATTRIBUTE_UV_0, mesh.uvs(uv_mapping))
+    .with_inserted_attribute(BevyMesh::ATTRIBUTE_COLOR, colors.to_vec())
+    .with_inserted_indices(Indices::U32(mesh.indices))
+}
+
+pub fn numeral_bevy_mesh(u: Numeral, style: &GlyphStyle, opts: &BevyMeshOptions) -> BevyMesh {
+    let mesh = style.numeral_mesh(u);
+    let color = linear(opts.tier_colors[u.diacritic_index() as usize]);
+    to_bevy_mesh(&mesh, &vec![color; mesh.vertices.len()], opts.uv_mapping)
+}
+
+pub fn uint_bevy_mesh(
+    u: &Uint,
+    layout_opts: &LayoutOptions,
+    style: &GlyphStyle,
+    opts: &BevyMeshOptions,
+) -> BevyMesh {
+    let layout = layout(u, layout_opts, style);
+    let mut mesh = Mesh::default();
+    let mut colors: Vec<[f32; 4]> = Vec::new();
+    for p in &layout.glyphs {
+        let glyph = style.numeral_mesh(p.numeral);
+        let color = linear(opts.tier_colors[p.numeral.diacritic_index() as usize]);
+        colors.extend(vec![color; glyph.vertices.len()]);
+        mesh.append(&glyph.translate(p.origin[0], p.origin[1]));
+    }
+    let separators = separator_mesh(&layout, layout_opts, style);
+    colors.extend(vec![
+        linear(opts.separator_color);
+        separators.vertices.len()
+    ]);
+    mesh.append(&separators);
+    to_bevy_mesh(&mesh, &colors, opts.uv_mapping)
+}
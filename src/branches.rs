@@ -0,0 +1,110 @@
+use crate::constants::constants::*;
+use crate::Numeral;
+
+// All rules below are written against the traditional branch order where
+// 子 (rat) is 0, which is exactly `Numeral::zee_index()`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BranchRelation {
+    Clash,
+    Harmony,
+    ThreeHarmony,
+    Direction,
+    Punishment,
+    Harm,
+    Destruction,
+}
+
+impl BranchRelation {
+    pub fn index(&self) -> u8 {
+        *self as u8
+    }
+    pub fn name(&self) -> &'static str {
+        BRANCH_RELATIONS[self.index() as usize]
+    }
+    pub fn name_cn(&self) -> &'static str {
+        BRANCH_RELATIONS_CN[self.index() as usize]
+    }
+}
+
+// 寅巳申 and 丑戌未 punish as a group, 子卯 as a pair.
+const PUNISHMENT_GROUPS: [[u8; 3]; 2] = [[2, 5, 8], [1, 10, 7]];
+const SELF_PUNISHMENT: [u8; 4] = [4, 6, 9, 11];
+
+fn in_group(g: &[u8; 3], b: u8) -> bool {
+    g.contains(&b)
+}
+
+pub fn is_clash(a: Numeral, b: Numeral) -> bool {
+    (a.zee_index() + 6) % 12 == b.zee_index()
+}
+
+pub fn is_harmony(a: Numeral, b: Numeral) -> bool {
+    (a.zee_index() + b.zee_index()) % 12 == 1
+}
+
+pub fn is_punishment(a: Numeral, b: Numeral) -> bool {
+    let (x, y) = (a.zee_index(), b.zee_index());
+    if x == y {
+        return SELF_PUNISHMENT.contains(&x);
+    }
+    PUNISHMENT_GROUPS
+        .iter()
+        .any(|g| in_group(g, x) && in_group(g, y))
+        || (x.min(y), x.max(y)) == (0, 3)
+}
+
+pub fn is_harm(a: Numeral, b: Numeral) -> bool {
+    (a.zee_index() + b.zee_index()) % 12 == 7
+}
+
+pub fn is_destruction(a: Numeral, b: Numeral) -> bool {
+    let (x, y) = (a.zee_index(), b.zee_index());
+    (x % 2 == 0 && (x + 9) % 12 == y) || (y % 2 == 0 && (y + 9) % 12 == x)
+}
+
+pub fn pair_relations(a: Numeral, b: Numeral) -> Vec<BranchRelation> {
+    let mut v: Vec<BranchRelation> = Vec::new();
+    if is_clash(a, b) {
+        v.push(BranchRelation::Clash)
+    }
+    if is_harmony(a, b) {
+        v.push(BranchRelation::Harmony)
+    }
+    if is_punishment(a, b) {
+        v.push(BranchRelation::Punishment)
+    }
+    if is_harm(a, b) {
+        v.push(BranchRelation::Harm)
+    }
+    if is_destruction(a, b) {
+        v.push(BranchRelation::Destruction)
+    }
+    v
+}
+
+fn distinct(b: [u8; 3]) -> bool {
+    b[0] != b[1] && b[1] != b[2] && b[0] != b[2]
+}
+
+pub fn triple_relations(a: Numeral, b: Numeral, c: Numeral) -> Vec<BranchRelation> {
+    let mut v: Vec<BranchRelation> = Vec::new();
+    let t = [a.zee_index(), b.zee_index(), c.zee_index()];
+    if !distinct(t) {
+        return v;
+    }
+    // 申子辰, 亥卯未, 寅午戌 and 巳酉丑 are the branches four apart
+    if t.iter().all(|&x| x % 4 == t[0] % 4) {
+        v.push(BranchRelation::ThreeHarmony)
+    }
+    // 寅卯辰, 巳午未, 申酉戌 and 亥子丑 are the seasonal thirds starting at 寅
+    if t.iter().all(|&x| (x + 10) % 12 / 3 == (t[0] + 10) % 12 / 3) {
+        v.push(BranchRelation::Direction)
+    }
+    if PUNISHMENT_GROUPS
+        .iter()
+        .any(|g| t.iter().all(|&x| in_group(g, x)))
+    {
+        v.push(BranchRelation::Punishment)
+    }
+    v
+}
@@ -29,9 +29,22 @@ pub mod constants {
         "aquarius",
     ];
     pub const ELEMENTS_CN: [&str; 5] = ["木", "火", "土", "金", "水"];
+    pub const WU_XING_RELATIONS: [&str; 5] =
+        ["same", "generates", "generated by", "overcomes", "overcome by"];
+    pub const WU_XING_RELATIONS_CN: [&str; 5] = ["比和", "我生", "生我", "我克", "克我"];
     pub const EARTHLY_BRANCHES_CN: [&str; 12] = [
         "亥", "子", "丑", "寅", "卯", "辰", "巳", "午", "未", "申", "酉", "戌",
     ];
+    pub const BRANCH_RELATIONS: [&str; 7] = [
+        "clash",
+        "harmony",
+        "three harmony",
+        "directional combination",
+        "punishment",
+        "harm",
+        "destruction",
+    ];
+    pub const BRANCH_RELATIONS_CN: [&str; 7] = ["冲", "六合", "三合", "三会", "刑", "害", "破"];
     pub const HEAVENLY_STEMS_CN: [&str; 10] =
         ["甲", "乙", "丙", "丁", "戊", "己", "庚", "辛", "壬", "癸"];
     pub const ANIMALS_CN: [&str; 12] = [
@@ -0,0 +1,325 @@
+use crate::mesh::Mesh;
+use crate::unit_diacritic::Diacritic;
+use std::f32::consts::PI;
+
+// Subdivision limits for adaptive sampling and Bezier fitting. The minimum
+// keeps symmetric curves like shay from looking straight at the first split.
+const MIN_DEPTH: u32 = 2;
+const MAX_DEPTH: u32 = 16;
+
+// The diacritics of `unit_diacritic` as closed-form curves. Each is the
+// region between an upper and a lower edge, both running left to right as t
+// goes from 0 to 1; ta is a disc split into its two halves.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DiacriticCurve {
+    diacritic: Diacritic,
+    pub radius: f32,
+}
+
+fn sub(a: [f32; 2], b: [f32; 2]) -> [f32; 2] {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn lerp(a: [f32; 2], b: [f32; 2], t: f32) -> [f32; 2] {
+    [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t]
+}
+
+fn distance(a: [f32; 2], b: [f32; 2]) -> f32 {
+    let d = sub(a, b);
+    (d[0] * d[0] + d[1] * d[1]).sqrt()
+}
+
+fn chord_distance(p: [f32; 2], a: [f32; 2], b: [f32; 2]) -> f32 {
+    let ab = sub(b, a);
+    let len = (ab[0] * ab[0] + ab[1] * ab[1]).sqrt();
+    if len == 0.0 {
+        return distance(p, a);
+    }
+    let ap = sub(p, a);
+    (ab[0] * ap[1] - ab[1] * ap[0]).abs() / len
+}
+
+impl DiacriticCurve {
+    pub fn new(diacritic: Diacritic, radius: f32) -> DiacriticCurve {
+        DiacriticCurve { diacritic, radius }
+    }
+    pub fn diacritic(&self) -> Diacritic {
+        self.diacritic
+    }
+
+    // Point and derivative with respect to t of one edge
+    fn edge(&self, upper: bool, t: f32) -> ([f32; 2], [f32; 2]) {
+        let r = self.radius;
+        let x = 2.0 * t - 1.0;
+        match (self.diacritic, upper) {
+            (Diacritic::Ta, _) => {
+                let rr = (r / 2.0).sqrt();
+                // the upper half sweeps over the top, the lower under the bottom
+                let (a, da) = if upper {
+                    (PI * (1.0 - t), -PI)
+                } else {
+                    (PI * (1.0 + t), PI)
+                };
+                (
+                    [rr * a.cos(), rr * a.sin()],
+                    [-rr * a.sin() * da, rr * a.cos() * da],
+                )
+            }
+            (Diacritic::Shey, _) => {
+                let shift = if upper { r } else { -r };
+                (
+                    [x, -(x * PI).sin() / (2.0 * PI) + shift],
+                    [2.0, -(x * PI).cos()],
+                )
+            }
+            (Diacritic::Ree, _) => {
+                let shift = if upper { 2.0 * r } else { 0.0 };
+                let c = (x * PI).cos();
+                (
+                    [x, -c.abs() / PI + shift],
+                    [2.0, 2.0 * c.signum() * (x * PI).sin()],
+                )
+            }
+            (Diacritic::Jo, _) => {
+                let shift = if upper { 0.0 } else { -2.0 * r };
+                ([x, (x / PI).abs() + shift], [2.0, 2.0 * x.signum() / PI])
+            }
+            (Diacritic::Wu, true) => ([x, -3.0 / PI * x * x + r], [2.0, -12.0 / PI * x]),
+            (Diacritic::Wu, false) => {
+                let a = (r * PI / 4.0).sqrt();
+                let xi = -a + 2.0 * a * t;
+                (
+                    [xi, -6.0 / PI * xi * xi - r / 2.0],
+                    [2.0 * a, -12.0 / PI * xi * 2.0 * a],
+                )
+            }
+        }
+    }
+    pub fn upper(&self, t: f32) -> [f32; 2] {
+        self.edge(true, t).0
+    }
+    pub fn lower(&self, t: f32) -> [f32; 2] {
+        self.edge(false, t).0
+    }
+    // Parameters where an edge turns a corner
+    pub fn corners(&self) -> Vec<f32> {
+        match self.diacritic {
+            Diacritic::Ree => vec![0.25, 0.75],
+            Diacritic::Jo => vec![0.5],
+            Diacritic::Ta | Diacritic::Shey | Diacritic::Wu => vec![],
+        }
+    }
+    fn pieces(&self) -> Vec<[f32; 2]> {
+        let mut ts = vec![0.0];
+        ts.extend(self.corners());
+        ts.push(1.0);
+        ts.windows(2).map(|w| [w[0], w[1]]).collect()
+    }
+    // The closed boundary, counter-clockwise: the lower edge left to right
+    // for t below 0.5, then the upper edge back
+    pub fn point(&self, t: f32) -> [f32; 2] {
+        let t = t.clamp(0.0, 1.0);
+        if t < 0.5 {
+            self.lower(2.0 * t)
+        } else {
+            self.upper(2.0 - 2.0 * t)
+        }
+    }
+
+    fn refine(&self, t0: f32, t1: f32, tolerance: f32, depth: u32, out: &mut Vec<f32>) {
+        let tm = (t0 + t1) / 2.0;
+        let flat = [true, false].iter().all(|&upper| {
+            let e = |t| self.edge(upper, t).0;
+            chord_distance(e(tm), e(t0), e(t1)) <= tolerance
+        });
+        if depth < MIN_DEPTH || (!flat && depth < MAX_DEPTH) {
+            self.refine(t0, tm, tolerance, depth + 1, out);
+            self.refine(tm, t1, tolerance, depth + 1, out);
+        } else {
+            out.push(t1);
+        }
+    }
+    // Parameters, shared by both edges, at which straight segments stay
+    // within `tolerance` of the curve
+    pub fn sample(&self, tolerance: f32) -> Vec<f32> {
+        let mut ts = vec![0.0];
+        for [t0, t1] in self.pieces() {
+            self.refine(t0, t1, tolerance, 0, &mut ts);
+        }
+        ts
+    }
+    pub fn polygon(&self, tolerance: f32) -> Vec<[f32; 2]> {
+        let ts = self.sample(tolerance);
+        let mut p: Vec<[f32; 2]> = ts.iter().map(|&t| self.lower(t)).collect();
+        p.extend(ts.iter().rev().map(|&t| self.upper(t)));
+        p.dedup_by(|a, b| distance(*a, *b) < 1e-6);
+        if p.len() > 1 && distance(p[0], p[p.len() - 1]) < 1e-6 {
+            p.pop();
+        }
+        p
+    }
+    // A strip of (upper, lower) pairs like `diacritic_vertices` builds for
+    // every diacritic but ta
+    pub fn mesh(&self, tolerance: f32) -> Mesh {
+        let ts = self.sample(tolerance);
+        let vertices = ts
+            .iter()
+            .flat_map(|&t| {
+                let (u, l) = (self.upper(t), self.lower(t));
+                [[u[0], u[1], 0.0], [l[0], l[1], 0.0]]
+            })
+            .collect();
+        // sampling always keeps both ends, so there is at least one step
+        let indices = Diacritic::Shey
+            .indices(ts.len() - 1)
+            .unwrap_or_else(|e| panic!("{}", e));
+        let mesh = Mesh::new(vertices, indices);
+        // ta's edges meet at both ends, collapsing the first and last triangles
+        let indices = mesh
+            .indices
+            .chunks_exact(3)
+            .zip(mesh.triangles())
+            .filter(|(_, [a, b, c])| {
+                ((b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])).abs() > 1e-12
+            })
+            .flat_map(|(t, _)| t.to_vec())
+            .collect();
+        Mesh::new(mesh.vertices, indices)
+    }
+
+    fn fit(
+        &self,
+        upper: bool,
+        t0: f32,
+        t1: f32,
+        tolerance: f32,
+        depth: u32,
+        out: &mut Vec<[[f32; 2]; 4]>,
+    ) {
+        let h = t1 - t0;
+        // tangents are taken just inside the piece so corners stay sharp
+        let eps = h * 1e-4;
+        let (p0, p3) = (self.edge(upper, t0).0, self.edge(upper, t1).0);
+        let (d0, d1) = (self.edge(upper, t0 + eps).1, self.edge(upper, t1 - eps).1);
+        let c = [
+            p0,
+            [p0[0] + d0[0] * h / 3.0, p0[1] + d0[1] * h / 3.0],
+            [p3[0] - d1[0] * h / 3.0, p3[1] - d1[1] * h / 3.0],
+            p3,
+        ];
+        let err = (1..8)
+            .map(|k| {
+                let s = k as f32 / 8.0;
+                distance(cubic_point(&c, s), self.edge(upper, t0 + s * h).0)
+            })
+            .fold(0.0, f32::max);
+        if err > tolerance && depth < MAX_DEPTH {
+            let tm = (t0 + t1) / 2.0;
+            self.fit(upper, t0, tm, tolerance, depth + 1, out);
+            self.fit(upper, tm, t1, tolerance, depth + 1, out);
+        } else {
+            out.push(c);
+        }
+    }
+    // Cubic segments of the closed boundary, in the same order as `point`
+    pub fn beziers(&self, tolerance: f32) -> BezierPath {
+        let mut lower: Vec<[[f32; 2]; 4]> = Vec::new();
+        let mut upper: Vec<[[f32; 2]; 4]> = Vec::new();
+        for [t0, t1] in self.pieces() {
+            self.fit(false, t0, t1, tolerance, 0, &mut lower);
+            self.fit(true, t0, t1, tolerance, 0, &mut upper);
+        }
+        let mut segments = lower;
+        let line = |a: [f32; 2], b: [f32; 2]| [a, lerp(a, b, 1.0 / 3.0), lerp(a, b, 2.0 / 3.0), b];
+        let (l1, u1) = (self.lower(1.0), self.upper(1.0));
+        if distance(l1, u1) > 1e-6 {
+            segments.push(line(l1, u1));
+        }
+        segments.extend(upper.iter().rev().map(|c| [c[3], c[2], c[1], c[0]]));
+        let (u0, l0) = (self.upper(0.0), self.lower(0.0));
+        if distance(u0, l0) > 1e-6 {
+            segments.push(line(u0, l0));
+        }
+        BezierPath { segments }
+    }
+}
+
+pub fn cubic_point(c: &[[f32; 2]; 4], s: f32) -> [f32; 2] {
+    let m = 1.0 - s;
+    let w = [m * m * m, 3.0 * m * m * s, 3.0 * m * s * s, s * s * s];
+    [0, 1].map(|k| (0..4).map(|i| w[i] * c[i][k]).sum())
+}
+
+// A closed run of cubic segments, each starting where the last one ended
+#[derive(Debug, Clone, PartialEq)]
+pub struct BezierPath {
+    pub segments: Vec<[[f32; 2]; 4]>,
+}
+
+impl BezierPath {
+    // Same matrix layout as `Mesh::transform`; cubics map exactly under it
+    pub fn transform(&self, m: [[f32; 3]; 2]) -> BezierPath {
+        let f = |p: [f32; 2]| {
+            [
+                m[0][0] * p[0] + m[0][1] * p[1] + m[0][2],
+                m[1][0] * p[0] + m[1][1] * p[1] + m[1][2],
+            ]
+        };
+        BezierPath {
+            segments: self.segments.iter().map(|c| c.map(f)).collect(),
+        }
+    }
+    pub fn to_svg_d(&self) -> String {
+        let Some(first) = self.segments.first() else {
+            return String::new();
+        };
+        // SVG's y axis points down
+        let mut d = format!("M{:.4} {:.4}", first[0][0], -first[0][1]);
+        for c in &self.segments {
+            d += &format!(
+                "C{:.4} {:.4} {:.4} {:.4} {:.4} {:.4}",
+                c[1][0], -c[1][1], c[2][0], -c[2][1], c[3][0], -c[3][1]
+            );
+        }
+        d + "Z"
+    }
+    // Quadratic segments for TrueType outlines. Each cubic is split evenly
+    // until the midpoint approximation is within `tolerance`.
+    pub fn to_quadratics(&self, tolerance: f32) -> Vec<[[f32; 2]; 3]> {
+        let mut q: Vec<[[f32; 2]; 3]> = Vec::new();
+        for c in &self.segments {
+            // bound on the error of one quadratic standing in for a cubic
+            let d = [0, 1].map(|k| c[3][k] - 3.0 * c[2][k] + 3.0 * c[1][k] - c[0][k]);
+            let err = 3f32.sqrt() / 36.0 * (d[0] * d[0] + d[1] * d[1]).sqrt();
+            let n = ((err / tolerance.max(f32::EPSILON)).cbrt().ceil() as usize).max(1);
+            for i in 0..n {
+                let (s0, s1) = (i as f32 / n as f32, (i + 1) as f32 / n as f32);
+                let p = split(c, s0, s1);
+                let ctrl = [0, 1].map(|k| (3.0 * (p[1][k] + p[2][k]) - p[0][k] - p[3][k]) / 4.0);
+                q.push([p[0], ctrl, p[3]]);
+            }
+        }
+        q
+    }
+}
+
+// The part of a cubic between parameters s0 and s1
+fn split(c: &[[f32; 2]; 4], s0: f32, s1: f32) -> [[f32; 2]; 4] {
+    let h = s1 - s0;
+    let d = |s: f32| {
+        let m = 1.0 - s;
+        [0, 1].map(|k| {
+            3.0 * (m * m * (c[1][k] - c[0][k])
+                + 2.0 * m * s * (c[2][k] - c[1][k])
+                + s * s * (c[3][k] - c[2][k]))
+        })
+    };
+    let (p0, p3) = (cubic_point(c, s0), cubic_point(c, s1));
+    let (d0, d1) = (d(s0), d(s1));
+    [
+        p0,
+        [p0[0] + d0[0] * h / 3.0, p0[1] + d0[1] * h / 3.0],
+        [p3[0] - d1[0] * h / 3.0, p3[1] - d1[1] * h / 3.0],
+        p3,
+    ]
+}
@@ -0,0 +1,121 @@
+// Writers for 3D interchange formats. Each takes a mesh, as produced by
+// `extrude`, and returns the file contents.
+use crate::geom::{cross, length};
+use crate::mesh::Mesh;
+
+fn face_normal(a: [f32; 3], b: [f32; 3], c: [f32; 3]) -> [f32; 3] {
+    let n = cross(a, b, c);
+    let len = length(n);
+    if len > 0.0 {
+        n.map(|c| c / len)
+    } else {
+        [0.0; 3]
+    }
+}
+
+pub fn to_obj(mesh: &Mesh) -> String {
+    let mut s = String::from("# hyxos_numerals\n");
+    for p in &mesh.vertices {
+        s += &format!("v {} {} {}\n", p[0], p[1], p[2]);
+    }
+    for t in mesh.indices.chunks_exact(3) {
+        s += &format!("f {} {} {}\n", t[0] + 1, t[1] + 1, t[2] + 1);
+    }
+    s
+}
+
+pub fn to_stl_ascii(mesh: &Mesh, name: &str) -> String {
+    let mut s = format!("solid {}\n", name);
+    for [a, b, c] in mesh.triangles() {
+        let n = face_normal(a, b, c);
+        s += &format!(
+            "  facet normal {} {} {}\n    outer loop\n",
+            n[0], n[1], n[2]
+        );
+        for p in [a, b, c] {
+            s += &format!("      vertex {} {} {}\n", p[0], p[1], p[2]);
+        }
+        s += "    endloop\n  endfacet\n";
+    }
+    s + &format!("endsolid {}\n", name)
+}
+
+pub fn to_stl_binary(mesh: &Mesh) -> Vec<u8> {
+    let mut b: Vec<u8> = vec![0; 80];
+    b[..15].copy_from_slice(b"hyxos_numerals ");
+    b.extend((mesh.triangle_count() as u32).to_le_bytes());
+    for [p0, p1, p2] in mesh.triangles() {
+        for p in [face_normal(p0, p1, p2), p0, p1, p2] {
+            for c in p {
+                b.extend(c.to_le_bytes());
+            }
+        }
+        b.extend(0u16.to_le_bytes());
+    }
+    b
+}
+
+fn pad4(b: &mut Vec<u8>, fill: u8) {
+    while !b.len().is_multiple_of(4) {
+        b.push(fill);
+    }
+}
+
+// Binary glTF 2.0 with a single mesh of u32-indexed triangles.
+pub fn to_glb(mesh: &Mesh) -> Vec<u8> {
+    let (v, idx) = (&mesh.vertices, &mesh.indices);
+    let mut bin: Vec<u8> = Vec::new();
+    let mut min = [f32::MAX; 3];
+    let mut max = [f32::MIN; 3];
+    for p in v {
+        for k in 0..3 {
+            min[k] = min[k].min(p[k]);
+            max[k] = max[k].max(p[k]);
+            bin.extend(p[k].to_le_bytes());
+        }
+    }
+    let positions_len = bin.len();
+    for i in idx {
+        bin.extend(i.to_le_bytes());
+    }
+    let indices_len = bin.len() - positions_len;
+    pad4(&mut bin, 0);
+    let mut json = format!(
+        concat!(
+            "{{\"asset\":{{\"version\":\"2.0\",\"generator\":\"hyxos_numerals\"}},",
+            "\"scene\":0,\"scenes\":[{{\"nodes\":[0]}}],\"nodes\":[{{\"mesh\":0}}],",
+            "\"meshes\":[{{\"primitives\":[{{\"attributes\":{{\"POSITION\":0}},\"indices\":1}}]}}],",
+            "\"buffers\":[{{\"byteLength\":{}}}],",
+            "\"bufferViews\":[{{\"buffer\":0,\"byteOffset\":0,\"byteLength\":{},\"target\":34962}},",
+            "{{\"buffer\":0,\"byteOffset\":{},\"byteLength\":{},\"target\":34963}}],",
+            "\"accessors\":[{{\"bufferView\":0,\"componentType\":5126,\"count\":{},\"type\":\"VEC3\",",
+            "\"min\":[{},{},{}],\"max\":[{},{},{}]}},",
+            "{{\"bufferView\":1,\"componentType\":5125,\"count\":{},\"type\":\"SCALAR\"}}]}}"
+        ),
+        bin.len(),
+        positions_len,
+        positions_len,
+        indices_len,
+        v.len(),
+        min[0],
+        min[1],
+        min[2],
+        max[0],
+        max[1],
+        max[2],
+        idx.len()
+    )
+    .into_bytes();
+    pad4(&mut json, b' ');
+    let mut glb: Vec<u8> = Vec::new();
+    glb.extend(b"glTF");
+    glb.extend(2u32.to_le_bytes());
+    glb.extend((12 + 8 + json.len() as u32 + 8 + bin.len() as u32).to_le_bytes());
+    glb.extend((json.len() as u32).to_le_bytes());
+    glb.extend(b"JSON");
+    glb.append(&mut json);
+    glb.extend((bin.len() as u32).to_le_bytes());
+    glb.extend(b"BIN\0");
+    glb.append(&mut bin);
+    glb
+}
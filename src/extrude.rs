@@ -0,0 +1,153 @@
+use crate::geom::{cross2d, xy};
+use crate::mesh::Mesh;
+use crate::style::GlyphStyle;
+use crate::Numeral;
+use std::collections::HashMap;
+
+const WELD_EPSILON: f32 = 1e-5;
+
+// Merges vertices closer than WELD_EPSILON, drops triangles that collapse and
+// turns the rest counter-clockwise in the xy plane.
+pub fn weld(mesh: &Mesh) -> Mesh {
+    let mut welded: Vec<[f32; 3]> = Vec::new();
+    let mut cells: HashMap<[i64; 3], u32> = HashMap::new();
+    let remap: Vec<u32> = mesh
+        .vertices
+        .iter()
+        .map(|p| {
+            let key = p.map(|c| (c / WELD_EPSILON).round() as i64);
+            *cells.entry(key).or_insert_with(|| {
+                welded.push(*p);
+                welded.len() as u32 - 1
+            })
+        })
+        .collect();
+    let mut tris: Vec<u32> = Vec::new();
+    for t in mesh.indices.chunks_exact(3) {
+        let [a, b, c] = [t[0], t[1], t[2]].map(|i| remap[i as usize]);
+        if a == b || b == c || a == c {
+            continue;
+        }
+        let area = cross2d(
+            xy(welded[a as usize]),
+            xy(welded[b as usize]),
+            xy(welded[c as usize]),
+        );
+        if area.abs() < WELD_EPSILON * WELD_EPSILON {
+            continue;
+        }
+        if area > 0.0 {
+            tris.extend([a, b, c]);
+        } else {
+            tris.extend([a, c, b]);
+        }
+    }
+    Mesh::new(welded, tris)
+}
+
+fn edge_uses(idx: &[u32]) -> HashMap<(u32, u32), (usize, isize)> {
+    // (min, max) -> (uses, sum of directions)
+    let mut edges: HashMap<(u32, u32), (usize, isize)> = HashMap::new();
+    for t in idx.chunks_exact(3) {
+        for (a, b) in [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])] {
+            let e = edges.entry((a.min(b), a.max(b))).or_insert((0, 0));
+            e.0 += 1;
+            e.1 += if a < b { 1 } else { -1 };
+        }
+    }
+    edges
+}
+
+// Directed edges used by exactly one triangle, keeping that triangle's winding.
+pub fn boundary_edges(idx: &[u32]) -> Vec<[u32; 2]> {
+    let edges = edge_uses(idx);
+    let mut boundary: Vec<[u32; 2]> = Vec::new();
+    for t in idx.chunks_exact(3) {
+        for (a, b) in [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])] {
+            if edges[&(a.min(b), a.max(b))].0 == 1 {
+                boundary.push([a, b]);
+            }
+        }
+    }
+    boundary
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct Watertightness {
+    // Edges with only one adjacent triangle
+    pub open_edges: usize,
+    // Edges shared by more than two triangles
+    pub non_manifold_edges: usize,
+    // Edges whose two triangles traverse it in the same direction
+    pub flipped_edges: usize,
+    // Vertices lying inside an edge they are not an end of, i.e. cracks
+    pub t_junctions: usize,
+}
+
+impl Watertightness {
+    pub fn is_watertight(&self) -> bool {
+        self.open_edges == 0
+            && self.non_manifold_edges == 0
+            && self.flipped_edges == 0
+            && self.t_junctions == 0
+    }
+}
+
+fn on_segment(p: [f32; 3], a: [f32; 3], b: [f32; 3]) -> bool {
+    let ab = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+    let ap = [p[0] - a[0], p[1] - a[1], p[2] - a[2]];
+    let len2 = ab[0] * ab[0] + ab[1] * ab[1] + ab[2] * ab[2];
+    if len2 < WELD_EPSILON * WELD_EPSILON {
+        return false;
+    }
+    let t = (ap[0] * ab[0] + ap[1] * ab[1] + ap[2] * ab[2]) / len2;
+    let d = [ap[0] - t * ab[0], ap[1] - t * ab[1], ap[2] - t * ab[2]];
+    let dist2 = d[0] * d[0] + d[1] * d[1] + d[2] * d[2];
+    t > WELD_EPSILON && t < 1.0 - WELD_EPSILON && dist2 < WELD_EPSILON * WELD_EPSILON
+}
+
+pub fn check_watertight(mesh: &Mesh) -> Watertightness {
+    let v = &mesh.vertices;
+    let mut w = Watertightness::default();
+    let edges = edge_uses(&mesh.indices);
+    for (&(a, b), (uses, dir)) in edges.iter() {
+        match uses {
+            1 => w.open_edges += 1,
+            2 => {
+                if *dir != 0 {
+                    w.flipped_edges += 1
+                }
+            }
+            _ => w.non_manifold_edges += 1,
+        }
+        let (pa, pb) = (v[a as usize], v[b as usize]);
+        w.t_junctions += v.iter().filter(|p| on_segment(**p, pa, pb)).count();
+    }
+    w
+}
+
+// Turns a flat triangle mesh into a closed solid between z = ±depth / 2.
+// Vertices are shared between the caps and the side walls, and every face
+// is wound counter-clockwise when seen from outside.
+pub fn extrude(mesh: &Mesh, depth: f32) -> Mesh {
+    let Mesh {
+        vertices: flat,
+        indices: tris,
+    } = weld(mesh);
+    let n = flat.len() as u32;
+    let half = depth / 2.0;
+    let mut solid: Vec<[f32; 3]> = flat.iter().map(|p| [p[0], p[1], half]).collect();
+    solid.extend(flat.iter().map(|p| [p[0], p[1], -half]));
+    let mut out: Vec<u32> = tris.clone();
+    for t in tris.chunks_exact(3) {
+        out.extend([t[0] + n, t[2] + n, t[1] + n]);
+    }
+    for [a, b] in boundary_edges(&tris) {
+        out.extend([a, a + n, b + n, a, b + n, b]);
+    }
+    Mesh::new(solid, out)
+}
+
+pub fn numeral_solid(u: Numeral, style: &GlyphStyle, depth: f32) -> Mesh {
+    extrude(&style.numeral_mesh(u), depth)
+}
@@ -0,0 +1,463 @@
+use crate::outline::{glyph_outline, numeral_outline, Path};
+use crate::style::GlyphStyle;
+use crate::{Numeral, Set};
+
+// TrueType-flavoured sfnt builder for the 60 numerals, with glyph outlines
+// taken from the composed numeral contours. The output is valid as .ttf or .otf.
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontOptions {
+    pub family_name: String,
+    // Numeral 0 maps here and numeral 59 to first_codepoint + 59
+    pub first_codepoint: u32,
+    pub units_per_em: u16,
+    // Font units per glyph unit
+    pub scale: f32,
+    // Monospaced advance; derived from the widest numeral when None
+    pub advance: Option<u16>,
+    pub side_bearing: u16,
+    pub glyph: GlyphStyle,
+    // Outlines diacritics with quadratic curves within this many glyph units
+    // of the exact shape instead of tracing the sampled mesh
+    pub curve_tolerance: Option<f32>,
+}
+
+impl Default for FontOptions {
+    fn default() -> FontOptions {
+        FontOptions {
+            family_name: "Hyxos Numerals".to_string(),
+            first_codepoint: 0xE000,
+            units_per_em: 1000,
+            scale: 600.0,
+            advance: None,
+            side_bearing: 50,
+            glyph: GlyphStyle::default(),
+            curve_tolerance: None,
+        }
+    }
+}
+
+fn in_private_use_area(first: u32) -> bool {
+    let Some(last) = first.checked_add(59) else {
+        return false;
+    };
+    [(0xE000, 0xF8FF), (0xF0000, 0xFFFFD), (0x100000, 0x10FFFD)]
+        .iter()
+        .any(|&(lo, hi)| first >= lo && last <= hi)
+}
+
+// Points carry whether they lie on the curve or are quadratic control points
+struct TtGlyph {
+    contours: Vec<Vec<([i16; 2], bool)>>,
+    bbox: [i16; 4],
+}
+
+impl TtGlyph {
+    fn empty() -> TtGlyph {
+        TtGlyph {
+            contours: Vec::new(),
+            bbox: [0; 4],
+        }
+    }
+    fn points(&self) -> usize {
+        self.contours.iter().map(|c| c.len()).sum()
+    }
+    // Control points can sit far outside the outline, so each curve counts
+    // with its extremes instead
+    fn update_bbox(&mut self) {
+        let mut bbox = [i16::MAX, i16::MAX, i16::MIN, i16::MIN];
+        let mut add = |p: [i16; 2]| {
+            bbox = [
+                bbox[0].min(p[0]),
+                bbox[1].min(p[1]),
+                bbox[2].max(p[0]),
+                bbox[3].max(p[1]),
+            ];
+        };
+        for c in &self.contours {
+            for (i, &(p, on)) in c.iter().enumerate() {
+                if on {
+                    add(p);
+                    continue;
+                }
+                let a = c[(i + c.len() - 1) % c.len()].0;
+                let b = c[(i + 1) % c.len()].0;
+                for k in 0..2 {
+                    let [a0, p0, b0] = [a[k], p[k], b[k]].map(|x| x as f32);
+                    let t = (a0 - p0) / (a0 - 2.0 * p0 + b0);
+                    if t > 0.0 && t < 1.0 {
+                        let m = 1.0 - t;
+                        let mut q = a;
+                        q[k] = (m * m * a0 + 2.0 * m * t * p0 + t * t * b0).round() as i16;
+                        add(q);
+                    }
+                }
+            }
+        }
+        self.bbox = if self.contours.is_empty() {
+            [0; 4]
+        } else {
+            bbox
+        };
+    }
+}
+
+// Moves the glyph body onto the baseline with its left edge at the side
+// bearing, in glyph units
+fn glyph_offset(u: Numeral, opts: &FontOptions) -> [f32; 2] {
+    let x0 = numeral_outline(u, &opts.glyph)
+        .contours
+        .iter()
+        .flat_map(|c| c.points.iter())
+        .fold(f32::MAX, |x, p| x.min(p[0]));
+    let apex = 0.5 * opts.glyph.height();
+    let lsb = opts.side_bearing as f32 / opts.scale;
+    [lsb - x0, apex]
+}
+
+fn glyph_path(u: Numeral, opts: &FontOptions) -> Path {
+    let [dx, dy] = glyph_offset(u, opts);
+    numeral_outline(u, &opts.glyph).translate(dx, dy)
+}
+
+// TrueType wants outer contours clockwise, the reverse of `Path`.
+fn build_glyph(path: &Path, scale: f32) -> TtGlyph {
+    let mut g = TtGlyph::empty();
+    for c in &path.contours {
+        let mut pts: Vec<([i16; 2], bool)> = Vec::new();
+        for p in c.reversed().points {
+            let q = (p.map(|c| (c * scale).round() as i16), true);
+            if pts.last() != Some(&q) && pts.first() != Some(&q) {
+                pts.push(q);
+            }
+        }
+        if pts.len() < 3 {
+            continue;
+        }
+        g.contours.push(pts);
+    }
+    g.update_bbox();
+    g
+}
+
+// The glyph body traced from its mesh and the diacritic as quadratic curves,
+// reversed like `build_glyph` does
+fn build_curved_glyph(u: Numeral, opts: &FontOptions, tolerance: f32) -> TtGlyph {
+    let [dx, dy] = glyph_offset(u, opts);
+    let mut g = build_glyph(&glyph_outline(u, &opts.glyph).translate(dx, dy), opts.scale);
+    let d = u.diacritic();
+    let mut m = opts.glyph.diacritic_transform(d);
+    m[0][2] += dx;
+    m[1][2] += dy;
+    let quadratics = opts
+        .glyph
+        .diacritic_curve(d)
+        .beziers(tolerance)
+        .transform(m)
+        .to_quadratics(tolerance);
+    let font_units = |p: [f32; 2]| p.map(|c| (c * opts.scale).round() as i16);
+    let contour = quadratics
+        .iter()
+        .rev()
+        .flat_map(|q| [(font_units(q[2]), true), (font_units(q[1]), false)])
+        .collect();
+    g.contours.push(contour);
+    g.update_bbox();
+    g
+}
+
+fn glyf_data(g: &TtGlyph) -> Vec<u8> {
+    let mut b: Vec<u8> = Vec::new();
+    if g.contours.is_empty() {
+        return b;
+    }
+    b.extend((g.contours.len() as i16).to_be_bytes());
+    for c in g.bbox {
+        b.extend(c.to_be_bytes());
+    }
+    let mut end = 0;
+    for c in &g.contours {
+        end += c.len();
+        b.extend((end as u16 - 1).to_be_bytes());
+    }
+    // no instructions, full-size deltas
+    b.extend(0u16.to_be_bytes());
+    b.extend(g.contours.iter().flatten().map(|(_, on)| *on as u8));
+    for axis in 0..2 {
+        let mut last = 0i16;
+        for (p, _) in g.contours.iter().flatten() {
+            b.extend((p[axis] - last).to_be_bytes());
+            last = p[axis];
+        }
+    }
+    while !b.len().is_multiple_of(4) {
+        b.push(0);
+    }
+    b
+}
+
+fn utf16be(s: &str) -> Vec<u8> {
+    s.encode_utf16().flat_map(|c| c.to_be_bytes()).collect()
+}
+
+fn name_table(opts: &FontOptions) -> Vec<u8> {
+    let postscript: String = opts
+        .family_name
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect();
+    let records = [
+        (1, opts.family_name.clone()),
+        (2, "Regular".to_string()),
+        (3, format!("{}-Regular", postscript)),
+        (4, opts.family_name.clone()),
+        (5, "Version 1.000".to_string()),
+        (6, format!("{}-Regular", postscript)),
+    ];
+    let mut b: Vec<u8> = Vec::new();
+    let mut strings: Vec<u8> = Vec::new();
+    b.extend(0u16.to_be_bytes());
+    b.extend((records.len() as u16).to_be_bytes());
+    b.extend((6 + 12 * records.len() as u16).to_be_bytes());
+    for (id, s) in records {
+        let s = utf16be(&s);
+        // Windows platform, Unicode BMP, US English
+        for x in [3u16, 1, 0x0409, id, s.len() as u16, strings.len() as u16] {
+            b.extend(x.to_be_bytes());
+        }
+        strings.extend(s);
+    }
+    b.extend(strings);
+    b
+}
+
+fn cmap_table(first: u32, glyphs: u16) -> Vec<u8> {
+    let last = first + glyphs as u32 - 1;
+    let bmp = last <= 0xFFFF;
+    let mut b: Vec<u8> = Vec::new();
+    let tables: u16 = if bmp { 2 } else { 1 };
+    b.extend(0u16.to_be_bytes());
+    b.extend(tables.to_be_bytes());
+    let header = 4 + 8 * tables as u32;
+    let format4_len = 32u32;
+    if bmp {
+        b.extend([0, 3, 0, 1]);
+        b.extend(header.to_be_bytes());
+    }
+    b.extend([0, 3, 0, 10]);
+    b.extend((header + if bmp { format4_len } else { 0 }).to_be_bytes());
+    if bmp {
+        // one segment for the numerals plus the mandatory 0xFFFF terminator
+        let delta = (1u16).wrapping_sub(first as u16);
+        for x in [4u16, 32, 0, 4, 4, 1, 0] {
+            b.extend(x.to_be_bytes());
+        }
+        for x in [last as u16, 0xFFFF, 0, first as u16, 0xFFFF, delta, 1, 0, 0] {
+            b.extend(x.to_be_bytes());
+        }
+    }
+    b.extend(12u16.to_be_bytes());
+    b.extend(0u16.to_be_bytes());
+    for x in [28u32, 0, 1, first, last, 1] {
+        b.extend(x.to_be_bytes());
+    }
+    b
+}
+
+fn checksum(b: &[u8]) -> u32 {
+    b.chunks(4).fold(0u32, |sum, c| {
+        let mut w = [0u8; 4];
+        w[..c.len()].copy_from_slice(c);
+        sum.wrapping_add(u32::from_be_bytes(w))
+    })
+}
+
+fn be(fields: &[i32], sizes: &[u8]) -> Vec<u8> {
+    let mut b: Vec<u8> = Vec::new();
+    for (f, s) in fields.iter().zip(sizes) {
+        match s {
+            2 => b.extend((*f as i16).to_be_bytes()),
+            4 => b.extend(f.to_be_bytes()),
+            _ => panic!("Can only write 2 or 4 byte fields"),
+        }
+    }
+    b
+}
+
+pub fn build_font(opts: &FontOptions) -> Vec<u8> {
+    if !in_private_use_area(opts.first_codepoint) {
+        panic!("Codepoints must lie within a Private Use Area!")
+    }
+    let mut glyphs: Vec<TtGlyph> = vec![TtGlyph::empty()];
+    let mut widest = 0.0f32;
+    for u in Set::new().values() {
+        let g = match opts.curve_tolerance {
+            Some(tolerance) => build_curved_glyph(u, opts, tolerance),
+            None => build_glyph(&glyph_path(u, opts), opts.scale),
+        };
+        widest = widest.max(g.bbox[2] as f32);
+        glyphs.push(g);
+    }
+    let num_glyphs = glyphs.len() as u16;
+    let lsb = opts.side_bearing as i32;
+    let advance = opts
+        .advance
+        .map_or((widest.ceil() as i32) + lsb, |a| a as i32);
+    let with_outline = || glyphs.iter().filter(|g| !g.contours.is_empty());
+    let x_min = with_outline().map(|g| g.bbox[0]).min().unwrap_or(0) as i32;
+    let x_max = with_outline().map(|g| g.bbox[2]).max().unwrap_or(0) as i32;
+    let y_min = (with_outline().map(|g| g.bbox[1]).min().unwrap_or(0) as i32).min(0);
+    let y_max = with_outline().map(|g| g.bbox[3]).max().unwrap_or(0) as i32;
+    let max_points = glyphs.iter().map(|g| g.points()).max().unwrap_or(0) as i32;
+    let max_contours = glyphs.iter().map(|g| g.contours.len()).max().unwrap_or(0) as i32;
+
+    let mut glyf: Vec<u8> = Vec::new();
+    let mut loca: Vec<u8> = Vec::new();
+    for g in &glyphs {
+        loca.extend((glyf.len() as u32).to_be_bytes());
+        glyf.extend(glyf_data(g));
+    }
+    loca.extend((glyf.len() as u32).to_be_bytes());
+
+    let mut hmtx: Vec<u8> = Vec::new();
+    for g in &glyphs {
+        hmtx.extend((advance as u16).to_be_bytes());
+        hmtx.extend(g.bbox[0].to_be_bytes());
+    }
+
+    let head = [
+        be(&[0x10000, 0x10000, 0, 0x5F0F3CF5], &[4, 4, 4, 4]),
+        be(&[3, opts.units_per_em as i32], &[2, 2]),
+        vec![0; 16],
+        be(
+            &[x_min, y_min, x_max, y_max, 0, 8, 2, 1, 0],
+            &[2, 2, 2, 2, 2, 2, 2, 2, 2],
+        ),
+    ]
+    .concat();
+    let hhea = be(
+        &[
+            0x10000,
+            y_max,
+            y_min,
+            0,
+            advance,
+            x_min,
+            advance - x_max,
+            x_max,
+            1,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            num_glyphs as i32,
+        ],
+        &[4, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2],
+    );
+    let maxp = be(
+        &[
+            0x10000,
+            num_glyphs as i32,
+            max_points,
+            max_contours,
+            0,
+            0,
+            2,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ],
+        &[4, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2],
+    );
+    let em = opts.units_per_em as i32;
+    let first = opts.first_codepoint.min(0xFFFF) as i32;
+    let last = opts.first_codepoint.saturating_add(59).min(0xFFFF) as i32;
+    let os2 = [
+        be(&[4, advance, 400, 5, 0], &[2, 2, 2, 2, 2]),
+        be(
+            &[
+                em / 2,
+                em / 2,
+                0,
+                em / 10,
+                em / 2,
+                em / 2,
+                0,
+                em / 3,
+                em / 20,
+                em / 4,
+                0,
+            ],
+            &[2; 11],
+        ),
+        vec![0; 10],
+        // Private Use Area is bit 60 of the Unicode ranges
+        be(&[0, 1 << 28, 0, 0], &[4, 4, 4, 4]),
+        b"HYXS".to_vec(),
+        be(
+            &[0x40, first, last, y_max, y_min, 0, y_max, -y_min, 1, 0],
+            &[2, 2, 2, 2, 2, 2, 2, 2, 4, 4],
+        ),
+        be(&[y_max / 2, y_max, 0, 32, 1], &[2, 2, 2, 2, 2]),
+    ]
+    .concat();
+    let post = [
+        be(&[0x30000, 0, -em / 10, em / 20, 1], &[4, 4, 2, 2, 4]),
+        vec![0; 16],
+    ]
+    .concat();
+
+    let tables: Vec<(&[u8; 4], Vec<u8>)> = vec![
+        (b"OS/2", os2),
+        (b"cmap", cmap_table(opts.first_codepoint, 60)),
+        (b"glyf", glyf),
+        (b"head", head),
+        (b"hhea", hhea),
+        (b"hmtx", hmtx),
+        (b"loca", loca),
+        (b"maxp", maxp),
+        (b"name", name_table(opts)),
+        (b"post", post),
+    ];
+    let n = tables.len() as u16;
+    let search = 1u16 << (15 - n.leading_zeros());
+    let mut font = be(
+        &[
+            0x10000,
+            n as i32,
+            search as i32 * 16,
+            search.trailing_zeros() as i32,
+            (n - search) as i32 * 16,
+        ],
+        &[4, 2, 2, 2, 2],
+    );
+    let mut offset = 12 + 16 * tables.len();
+    let mut body: Vec<u8> = Vec::new();
+    let mut head_offset = 0;
+    for (tag, data) in &tables {
+        if *tag == b"head" {
+            head_offset = offset;
+        }
+        font.extend(*tag);
+        font.extend(checksum(data).to_be_bytes());
+        font.extend((offset as u32).to_be_bytes());
+        font.extend((data.len() as u32).to_be_bytes());
+        body.extend(data);
+        while !body.len().is_multiple_of(4) {
+            body.push(0);
+        }
+        offset = 12 + 16 * tables.len() + body.len();
+    }
+    font.extend(body);
+    let adjustment = 0xB1B0AFBAu32.wrapping_sub(checksum(&font));
+    font[head_offset + 8..head_offset + 12].copy_from_slice(&adjustment.to_be_bytes());
+    font
+}
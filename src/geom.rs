@@ -0,0 +1,60 @@
+// Small geometry and raster helpers shared by the renderers. Points are in
+// glyph units with y pointing up unless a caller says otherwise.
+
+pub(crate) fn xy(p: [f32; 3]) -> [f32; 2] {
+    [p[0], p[1]]
+}
+
+// Normal of the triangle abc, twice its area long
+pub(crate) fn cross(a: [f32; 3], b: [f32; 3], c: [f32; 3]) -> [f32; 3] {
+    let u = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+    let w = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+    [
+        u[1] * w[2] - u[2] * w[1],
+        u[2] * w[0] - u[0] * w[2],
+        u[0] * w[1] - u[1] * w[0],
+    ]
+}
+
+pub(crate) fn length(n: [f32; 3]) -> f32 {
+    (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt()
+}
+
+// Positive when a, b and c turn counter-clockwise
+pub(crate) fn cross2d(a: [f32; 2], b: [f32; 2], c: [f32; 2]) -> f32 {
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+pub(crate) fn segment_distance(p: [f32; 2], a: [f32; 2], b: [f32; 2]) -> f32 {
+    let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+    let len = dx * dx + dy * dy;
+    let t = if len > 0.0 {
+        (((p[0] - a[0]) * dx + (p[1] - a[1]) * dy) / len).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    (p[0] - a[0] - t * dx).hypot(p[1] - a[1] - t * dy)
+}
+
+// Smallest [x0, y0, x1, y1] covering every box, None when there are none
+pub(crate) fn union(bounds: impl IntoIterator<Item = [f32; 4]>) -> Option<[f32; 4]> {
+    bounds.into_iter().reduce(|a, b| {
+        [
+            a[0].min(b[0]),
+            a[1].min(b[1]),
+            a[2].max(b[2]),
+            a[3].max(b[3]),
+        ]
+    })
+}
+
+pub(crate) fn point_bounds(points: impl IntoIterator<Item = [f32; 2]>) -> Option<[f32; 4]> {
+    union(points.into_iter().map(|p| [p[0], p[1], p[0], p[1]]))
+}
+
+// Binary PGM (P5) or PPM (P6) with 8-bit samples
+pub(crate) fn netpbm(magic: &str, width: usize, height: usize, samples: &[u8]) -> Vec<u8> {
+    let mut b = format!("{}\n{} {}\n255\n", magic, width, height).into_bytes();
+    b.extend(samples);
+    b
+}
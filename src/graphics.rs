@@ -0,0 +1,276 @@
+// Escape sequences that show a raster image inline in terminals speaking the
+// Sixel or Kitty graphics protocols.
+use crate::mesh::Mesh;
+use crate::png::zlib;
+use crate::raster::{rasterize, Bitmap, RasterOptions};
+use crate::style::GlyphStyle;
+use crate::typeset::{typeset_mesh, LayoutOptions};
+use crate::{Numeral, Uint};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ImageOptions {
+    pub raster: RasterOptions,
+    // Largest image wanted in pixels, padding included. When either is set
+    // the raster scale is replaced by the largest one that fits.
+    pub max_width: Option<usize>,
+    pub max_height: Option<usize>,
+}
+
+impl Default for ImageOptions {
+    fn default() -> ImageOptions {
+        ImageOptions {
+            raster: RasterOptions {
+                scale: 32.0,
+                ..Default::default()
+            },
+            max_width: None,
+            max_height: None,
+        }
+    }
+}
+
+// Pixels per glyph unit that fit `bounds` and the padding inside the limits.
+// A limit at or below the padding still leaves the glyph a single pixel, so
+// the image ends up only that much larger than the padding.
+pub fn fit_scale(bounds: [f32; 4], opts: &ImageOptions) -> f32 {
+    let [x0, y0, x1, y1] = bounds;
+    let pad = 2 * opts.raster.padding;
+    let fit = |max: Option<usize>, extent: f32| {
+        max.map(|m| {
+            // shaved so the rounding up in `rasterize` stays within the limit
+            m.saturating_sub(pad).max(1) as f32 / extent * (1.0 - 1e-5)
+        })
+    };
+    match (fit(opts.max_width, x1 - x0), fit(opts.max_height, y1 - y0)) {
+        (Some(w), Some(h)) => w.min(h),
+        (Some(s), None) | (None, Some(s)) => s,
+        (None, None) => opts.raster.scale,
+    }
+}
+
+fn image(mesh: &Mesh, opts: &ImageOptions) -> Bitmap {
+    let raster = RasterOptions {
+        scale: fit_scale(mesh.bounds2d(), opts),
+        ..opts.raster
+    };
+    rasterize(mesh, &raster)
+}
+
+pub fn numeral_image(u: Numeral, style: &GlyphStyle, opts: &ImageOptions) -> Bitmap {
+    image(&style.numeral_mesh(u), opts)
+}
+
+pub fn uint_image(
+    u: &Uint,
+    layout: &LayoutOptions,
+    style: &GlyphStyle,
+    opts: &ImageOptions,
+) -> Bitmap {
+    image(&typeset_mesh(u, layout, style), opts)
+}
+
+// At most 256 colors for the Sixel registers. Colors are merged by dropping
+// low bits until few enough buckets remain, each taking its mean color.
+fn palette(bmp: &Bitmap) -> (Vec<[u8; 3]>, Vec<Option<usize>>) {
+    let rgb = bmp.rgb();
+    let opaque = |i: usize| bmp.pixels[i][3] > 0;
+    for shift in 0..8 {
+        let key = |c: [u8; 3]| c.map(|k| k >> shift);
+        let mut keys: Vec<[u8; 3]> = (0..rgb.len())
+            .filter(|&i| opaque(i))
+            .map(|i| key(rgb[i]))
+            .collect();
+        keys.sort();
+        keys.dedup();
+        if keys.len() > 256 {
+            continue;
+        }
+        let mut sums = vec![[0u32; 4]; keys.len()];
+        let index: Vec<Option<usize>> = (0..rgb.len())
+            .map(|i| {
+                if !opaque(i) {
+                    return None;
+                }
+                let k = keys.binary_search(&key(rgb[i])).unwrap();
+                for c in 0..3 {
+                    sums[k][c] += rgb[i][c] as u32;
+                }
+                sums[k][3] += 1;
+                Some(k)
+            })
+            .collect();
+        let colors = sums
+            .iter()
+            .map(|s| [0, 1, 2].map(|c| ((s[c] + s[3] / 2) / s[3]) as u8))
+            .collect();
+        return (colors, index);
+    }
+    unreachable!()
+}
+
+fn sixel_run(out: &mut String, bits: u8, n: usize) {
+    let c = (63 + bits) as char;
+    if n > 3 {
+        *out += &format!("!{}{}", n, c);
+    } else {
+        out.extend(std::iter::repeat_n(c, n));
+    }
+}
+
+// Transparent pixels are left unpainted, the rest are flattened onto white
+pub fn encode_sixel(bmp: &Bitmap) -> String {
+    let (colors, index) = palette(bmp);
+    let index = &index;
+    let (w, h) = (bmp.width, bmp.height);
+    // P2 = 1 keeps unpainted pixels at the terminal background
+    let mut out = format!("\x1bP0;1;0q\"1;1;{};{}", w, h);
+    for (i, c) in colors.iter().enumerate() {
+        let [r, g, b] = c.map(|k| (k as u32 * 100 + 127) / 255);
+        out += &format!("#{};2;{};{};{}", i, r, g, b);
+    }
+    for band in 0..h.div_ceil(6) {
+        if band > 0 {
+            out.push('-');
+        }
+        let rows = 6 * band..(6 * band + 6).min(h);
+        let mut used: Vec<usize> = rows
+            .clone()
+            .flat_map(|y| (0..w).filter_map(move |x| index[y * w + x]))
+            .collect();
+        used.sort();
+        used.dedup();
+        for (n, k) in used.iter().enumerate() {
+            if n > 0 {
+                out.push('$');
+            }
+            out += &format!("#{}", k);
+            let column = |x: usize| {
+                rows.clone()
+                    .filter(|&y| index[y * w + x] == Some(*k))
+                    .fold(0u8, |b, y| b | 1 << (y - 6 * band))
+            };
+            let (mut run, mut count) = (column(0), 0);
+            let mut pending = String::new();
+            for x in 0..w {
+                let bits = column(x);
+                if bits != run {
+                    sixel_run(&mut pending, run, count);
+                    (run, count) = (bits, 0);
+                }
+                count += 1;
+            }
+            // a trailing empty run needs no characters
+            if run != 0 {
+                sixel_run(&mut pending, run, count);
+            }
+            out += &pending;
+        }
+    }
+    out += "\x1b\\";
+    out
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum KittyFormat {
+    #[default]
+    Png,
+    // Zlib compressed 8-bit RGBA
+    Rgba,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct KittyOptions {
+    pub format: KittyFormat,
+    // Terminal cells the image is scaled to cover; its own size when None
+    pub columns: Option<usize>,
+    pub rows: Option<usize>,
+}
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [0, 1, 2].map(|i| chunk.get(i).copied().unwrap_or(0) as u32);
+        let n = b[0] << 16 | b[1] << 8 | b[2];
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+// The payload goes out in chunks of at most 4096 base64 characters, the
+// first carrying the control keys and each marking whether more follow
+pub fn encode_kitty(bmp: &Bitmap, opts: &KittyOptions) -> String {
+    let mut keys = "a=T,q=2".to_string();
+    let data = match opts.format {
+        KittyFormat::Png => {
+            keys += ",f=100";
+            bmp.to_png()
+        }
+        KittyFormat::Rgba => {
+            keys += &format!(",f=32,s={},v={},o=z", bmp.width, bmp.height);
+            zlib(&bmp.pixels.concat())
+        }
+    };
+    if let Some(c) = opts.columns {
+        keys += &format!(",c={}", c);
+    }
+    if let Some(r) = opts.rows {
+        keys += &format!(",r={}", r);
+    }
+    let payload = base64(&data);
+    let chunks: Vec<&[u8]> = payload.as_bytes().chunks(4096).collect();
+    let mut out = String::new();
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = (i + 1 < chunks.len()) as u8;
+        let control = if i == 0 {
+            format!("{},m={}", keys, more)
+        } else {
+            format!("m={}", more)
+        };
+        out += &format!(
+            "\x1b_G{};{}\x1b\\",
+            control,
+            std::str::from_utf8(chunk).unwrap()
+        );
+    }
+    out
+}
+
+pub fn numeral_sixel(u: Numeral, style: &GlyphStyle, opts: &ImageOptions) -> String {
+    encode_sixel(&numeral_image(u, style, opts))
+}
+
+pub fn uint_sixel(
+    u: &Uint,
+    layout: &LayoutOptions,
+    style: &GlyphStyle,
+    opts: &ImageOptions,
+) -> String {
+    encode_sixel(&uint_image(u, layout, style, opts))
+}
+
+pub fn numeral_kitty(
+    u: Numeral,
+    style: &GlyphStyle,
+    opts: &ImageOptions,
+    kitty: &KittyOptions,
+) -> String {
+    encode_kitty(&numeral_image(u, style, opts), kitty)
+}
+
+pub fn uint_kitty(
+    u: &Uint,
+    layout: &LayoutOptions,
+    style: &GlyphStyle,
+    opts: &ImageOptions,
+    kitty: &KittyOptions,
+) -> String {
+    encode_kitty(&uint_image(u, layout, style, opts), kitty)
+}
@@ -1,12 +1,41 @@
 #![feature(more_float_constants)]
 
+pub mod atlas;
+#[cfg(feature = "bevy")]
+pub mod bevy;
+pub mod branches;
+pub mod curve;
+pub mod export;
+pub mod extrude;
+pub mod font;
+pub mod graphics;
+pub mod mesh;
+pub mod morph;
+pub mod outline;
+pub mod pdf;
+pub mod pick;
+pub mod png;
+pub mod raster;
+pub mod recognize;
+pub mod sdf;
+pub mod solar;
+pub mod style;
+pub mod svg;
+pub mod terminal;
+pub mod typeset;
 pub mod unit_diacritic;
 pub mod unit_glyph;
+pub mod unit_numeral;
+pub mod wu_xing;
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Numeral(u8);
 
+pub use unit_diacritic::Diacritic;
+pub use unit_glyph::Glyph;
+
 mod constants;
+mod geom;
 
 use constants::constants::*;
 
@@ -18,23 +47,23 @@ impl Numeral {
             Numeral(u)
         }
     }
+    pub fn from_parts(diacritic: Diacritic, glyph: Glyph) -> Numeral {
+        Numeral(diacritic.index() * 12 + glyph.index())
+    }
     pub fn new_from_enc(n: &str) -> Numeral {
-        let mut di: Option<usize> = Some(0);
-        let mut dd: Option<usize> = Some(0);
+        let mut di: Option<Diacritic> = Some(Diacritic::Ta);
+        let mut dd: Option<Glyph> = Some(Glyph::Zo);
         for (i, c) in n.chars().enumerate() {
             match i {
-                0 => di = DIACRITIC_CHARS.iter().position(|&t| t == c),
-                1 => dd = DUODECIMALS.iter().position(|&u| u == c),
+                0 => di = Diacritic::from_char(c),
+                1 => dd = Glyph::from_char(c),
                 _ => panic!("Invalid encoding!"),
             }
         }
-        fn check_encoding(o: Option<usize>) -> usize {
-            match o {
-                Some(o) => o,
-                None => panic!("Invalid encoding!"),
-            }
+        match (di, dd) {
+            (Some(d), Some(g)) => Numeral::from_parts(d, g),
+            _ => panic!("Invalid encoding!"),
         }
-        Numeral(((check_encoding(di) * 12) + check_encoding(dd)) as u8)
     }
     pub fn u(&self) -> u8 {
         self.0
@@ -42,45 +71,54 @@ impl Numeral {
     pub fn diacritic_index(&self) -> u8 {
         self.0 / 12
     }
+    pub fn diacritic(&self) -> Diacritic {
+        Diacritic::ALL[self.diacritic_index() as usize]
+    }
     pub fn duodecimal_index(&self) -> u8 {
         self.0 % 12
     }
+    pub fn glyph(&self) -> Glyph {
+        Glyph::ALL[self.duodecimal_index() as usize]
+    }
+    pub fn parts(&self) -> (Diacritic, Glyph) {
+        (self.diacritic(), self.glyph())
+    }
     pub fn diacritic_char(&self) -> char {
-        DIACRITIC_CHARS[self.diacritic_index() as usize]
+        self.diacritic().char()
     }
     pub fn diacritic_name(&self) -> &str {
-        DIACRITIC_NAME[self.diacritic_index() as usize]
+        self.diacritic().name()
     }
     pub fn duodecimal_char(&self) -> char {
-        DUODECIMALS[self.duodecimal_index() as usize]
+        self.glyph().char()
     }
     pub fn duodecimal_name(&self) -> &str {
-        DUODECIMAL_NAME[self.duodecimal_index() as usize]
+        self.glyph().name()
     }
     pub fn sexagesimal_name(&self) -> String {
         self.diacritic_name().to_owned() + self.duodecimal_name()
     }
     
     pub fn spoken_name(&self) -> String {
-        match (self.diacritic_index(), self.duodecimal_index()) {
+        match self.parts() {
             // ta tier - just the glyph name
-            (0, _) => self.duodecimal_name().to_string(),
+            (Diacritic::Ta, g) => g.name().to_string(),
             // diacritic + zo = just the diacritic
-            (_, 0) => self.diacritic_name().to_string(),
+            (d, Glyph::Zo) => d.name().to_string(),
             // Contractions with hyx (6)
-            (1, 6) => "shex".to_string(),
-            (2, 6) => "reex".to_string(),
-            (3, 6) => "jox".to_string(),
-            (4, 6) => "wux".to_string(),
+            (Diacritic::Shey, Glyph::Hyx) => "shex".to_string(),
+            (Diacritic::Ree, Glyph::Hyx) => "reex".to_string(),
+            (Diacritic::Jo, Glyph::Hyx) => "jox".to_string(),
+            (Diacritic::Wu, Glyph::Hyx) => "wux".to_string(),
             // Contractions with awk (8)
-            (1, 8) => "shek".to_string(),
-            (2, 8) => "reek".to_string(),
-            (3, 8) => "jok".to_string(),
-            (4, 8) => "wuk".to_string(),
+            (Diacritic::Shey, Glyph::Awk) => "shek".to_string(),
+            (Diacritic::Ree, Glyph::Awk) => "reek".to_string(),
+            (Diacritic::Jo, Glyph::Awk) => "jok".to_string(),
+            (Diacritic::Wu, Glyph::Awk) => "wuk".to_string(),
             // Default: diacritic + glyph
             // Special case for shey tier - drop the "y" in compounds
-            (1, _) => "she".to_owned() + self.duodecimal_name(),
-            _ => self.diacritic_name().to_owned() + self.duodecimal_name()
+            (Diacritic::Shey, g) => "she".to_owned() + g.name(),
+            (d, g) => d.name().to_owned() + g.name()
         }
     }
     pub fn encoding(&self) -> String {
@@ -213,10 +251,62 @@ impl Set {
         Set(set)
     }
     pub fn nat_sort(&self) -> Set {
+        self.sort_by_scheme(SortScheme::Natural)
+    }
+    pub fn sort_by_scheme(&self, o: SortScheme) -> Set {
         let mut set: Vec<Numeral> = self.values();
-        set.sort_by(|a, b| a.natural_order_index().cmp(&b.natural_order_index()));
+        set.sort_by(|a, b| o.compare(a, b));
         Set(set)
     }
+    pub fn filter(&self, keep: impl Fn(&Numeral) -> bool) -> Set {
+        Set(self.0.iter().copied().filter(|n| keep(n)).collect())
+    }
+    // Walks the whole set once, starting at `start` and wrapping back to the front.
+    // Yields nothing when `start` is not in the set.
+    pub fn cycle_from(&self, start: Numeral) -> impl Iterator<Item = Numeral> + '_ {
+        let (head, tail) = match self.0.iter().position(|n| *n == start) {
+            Some(i) => self.0.split_at(i),
+            None => (&[][..], &[][..]),
+        };
+        tail.iter().chain(head).copied()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SortScheme {
+    Value,
+    Natural,
+    Generating,
+    HeavenlyStem,
+    Western,
+    Shape,
+    Spoken,
+}
+
+impl SortScheme {
+    pub const ALL: [SortScheme; 7] = [
+        SortScheme::Value,
+        SortScheme::Natural,
+        SortScheme::Generating,
+        SortScheme::HeavenlyStem,
+        SortScheme::Western,
+        SortScheme::Shape,
+        SortScheme::Spoken,
+    ];
+    // Ties are always broken by value so every scheme yields a total, stable order.
+    pub fn compare(&self, a: &Numeral, b: &Numeral) -> std::cmp::Ordering {
+        let primary = match self {
+            SortScheme::Value => std::cmp::Ordering::Equal,
+            SortScheme::Natural => a.natural_order_index().cmp(&b.natural_order_index()),
+            SortScheme::Generating => a.generating_index().cmp(&b.generating_index()),
+            SortScheme::HeavenlyStem => a.heavenly_stem_index().cmp(&b.heavenly_stem_index()),
+            // zee_index puts aries first and pisces last
+            SortScheme::Western => a.zee_index().cmp(&b.zee_index()),
+            SortScheme::Shape => (a.row_index(), a.col_index()).cmp(&(b.row_index(), b.col_index())),
+            SortScheme::Spoken => a.spoken_name().cmp(&b.spoken_name()),
+        };
+        primary.then(a.u().cmp(&b.u()))
+    }
 }
 
 #[derive(Debug, Clone)]
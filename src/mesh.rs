@@ -0,0 +1,213 @@
+use crate::geom::{cross, length};
+
+const DEGENERATE_AREA: f32 = 1e-10;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum UvMapping {
+    // Glyph coordinates times a scale, so textures tile at the same size on
+    // every numeral
+    Planar(f32),
+    // The bounding box stretched over the unit square
+    BoundingBox,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MeshIssue {
+    // The index buffer length is not a multiple of three
+    TrailingIndices(usize),
+    OutOfRange { triangle: usize, index: u32 },
+    // A repeated index or zero area
+    Degenerate { triangle: usize },
+}
+
+// Indexed triangle list shared by every geometry producer in the crate.
+// Transforms act in the xy plane and leave z untouched.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Mesh {
+    pub vertices: Vec<[f32; 3]>,
+    pub indices: Vec<u32>,
+}
+
+impl Mesh {
+    pub fn new(vertices: Vec<[f32; 3]>, indices: Vec<u32>) -> Mesh {
+        Mesh { vertices, indices }
+    }
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len() / 3
+    }
+    pub fn triangles(&self) -> impl Iterator<Item = [[f32; 3]; 3]> + '_ {
+        self.indices
+            .chunks_exact(3)
+            .map(|t| [t[0], t[1], t[2]].map(|i| self.vertices[i as usize]))
+    }
+    // Applies x' = a·x + b·y + c, y' = d·x + e·y + f
+    pub fn transform(&self, m: [[f32; 3]; 2]) -> Mesh {
+        let mesh = Mesh {
+            vertices: self
+                .vertices
+                .iter()
+                .map(|p| {
+                    [
+                        m[0][0] * p[0] + m[0][1] * p[1] + m[0][2],
+                        m[1][0] * p[0] + m[1][1] * p[1] + m[1][2],
+                        p[2],
+                    ]
+                })
+                .collect(),
+            indices: self.indices.clone(),
+        };
+        // a mirroring map would turn every triangle inside out
+        if m[0][0] * m[1][1] - m[0][1] * m[1][0] < 0.0 {
+            mesh.reverse_winding()
+        } else {
+            mesh
+        }
+    }
+    pub fn translate(&self, dx: f32, dy: f32) -> Mesh {
+        self.transform([[1.0, 0.0, dx], [0.0, 1.0, dy]])
+    }
+    pub fn scale(&self, sx: f32, sy: f32) -> Mesh {
+        self.transform([[sx, 0.0, 0.0], [0.0, sy, 0.0]])
+    }
+    // Counter-clockwise about the origin
+    pub fn rotate(&self, radians: f32) -> Mesh {
+        let (s, c) = radians.sin_cos();
+        self.transform([[c, -s, 0.0], [s, c, 0.0]])
+    }
+    // Shears x by kx·y and y by ky·x
+    pub fn skew(&self, kx: f32, ky: f32) -> Mesh {
+        self.transform([[1.0, kx, 0.0], [ky, 1.0, 0.0]])
+    }
+    pub fn reverse_winding(&self) -> Mesh {
+        Mesh {
+            vertices: self.vertices.clone(),
+            indices: self
+                .indices
+                .chunks_exact(3)
+                .flat_map(|t| [t[0], t[2], t[1]])
+                .collect(),
+        }
+    }
+    pub fn merge(&self, other: &Mesh) -> Mesh {
+        let mut m = self.clone();
+        m.append(other);
+        m
+    }
+    pub fn append(&mut self, other: &Mesh) {
+        let offset = self.vertices.len() as u32;
+        self.vertices.extend(&other.vertices);
+        self.indices
+            .extend(other.indices.iter().map(|i| i + offset));
+    }
+    pub fn concat(meshes: &[Mesh]) -> Mesh {
+        let mut m = Mesh::default();
+        for other in meshes {
+            m.append(other);
+        }
+        m
+    }
+    // [min, max] corners; an empty mesh has inverted infinite bounds
+    pub fn bounds(&self) -> [[f32; 3]; 2] {
+        self.vertices.iter().fold(
+            [[f32::INFINITY; 3], [f32::NEG_INFINITY; 3]],
+            |[lo, hi], p| {
+                [
+                    [lo[0].min(p[0]), lo[1].min(p[1]), lo[2].min(p[2])],
+                    [hi[0].max(p[0]), hi[1].max(p[1]), hi[2].max(p[2])],
+                ]
+            },
+        )
+    }
+    // [x0, y0, x1, y1]
+    pub fn bounds2d(&self) -> [f32; 4] {
+        let [lo, hi] = self.bounds();
+        [lo[0], lo[1], hi[0], hi[1]]
+    }
+    // Total surface area; overlapping triangles are counted twice
+    pub fn area(&self) -> f32 {
+        self.triangles()
+            .map(|[a, b, c]| length(cross(a, b, c)) / 2.0)
+            .sum()
+    }
+    // Turns every triangle counter-clockwise in the xy plane, which is what
+    // the glyph and diacritic index tables do not guarantee.
+    pub fn wind_ccw(&self) -> Mesh {
+        let mut indices: Vec<u32> = Vec::with_capacity(self.indices.len());
+        for (t, [a, b, c]) in self.indices.chunks_exact(3).zip(self.triangles()) {
+            if cross(a, b, c)[2] < 0.0 {
+                indices.extend([t[0], t[2], t[1]]);
+            } else {
+                indices.extend_from_slice(t);
+            }
+        }
+        Mesh::new(self.vertices.clone(), indices)
+    }
+    // Area weighted average of the adjacent face normals. Vertices no
+    // triangle uses face +z like a flat glyph.
+    pub fn normals(&self) -> Vec<[f32; 3]> {
+        let mut sum = vec![[0.0f32; 3]; self.vertices.len()];
+        for (t, [a, b, c]) in self.indices.chunks_exact(3).zip(self.triangles()) {
+            let n = cross(a, b, c);
+            for &i in t {
+                for k in 0..3 {
+                    sum[i as usize][k] += n[k];
+                }
+            }
+        }
+        sum.into_iter()
+            .map(|n| {
+                let len = length(n);
+                if len > 0.0 {
+                    n.map(|c| c / len)
+                } else {
+                    [0.0, 0.0, 1.0]
+                }
+            })
+            .collect()
+    }
+    // Texture coordinates with v growing downwards like image rows
+    pub fn uvs(&self, mapping: UvMapping) -> Vec<[f32; 2]> {
+        match mapping {
+            UvMapping::Planar(s) => self
+                .vertices
+                .iter()
+                .map(|p| [p[0] * s, -p[1] * s])
+                .collect(),
+            UvMapping::BoundingBox => {
+                let [x0, y0, x1, y1] = self.bounds2d();
+                let (w, h) = ((x1 - x0).max(f32::EPSILON), (y1 - y0).max(f32::EPSILON));
+                self.vertices
+                    .iter()
+                    .map(|p| [(p[0] - x0) / w, (y1 - p[1]) / h])
+                    .collect()
+            }
+        }
+    }
+    pub fn validate(&self) -> Vec<MeshIssue> {
+        let mut issues: Vec<MeshIssue> = Vec::new();
+        if !self.indices.len().is_multiple_of(3) {
+            issues.push(MeshIssue::TrailingIndices(self.indices.len() % 3));
+        }
+        for (triangle, t) in self.indices.chunks_exact(3).enumerate() {
+            if let Some(&index) = t.iter().find(|&&i| i as usize >= self.vertices.len()) {
+                issues.push(MeshIssue::OutOfRange { triangle, index });
+                continue;
+            }
+            let [a, b, c] = [t[0], t[1], t[2]].map(|i| self.vertices[i as usize]);
+            if t[0] == t[1]
+                || t[1] == t[2]
+                || t[0] == t[2]
+                || length(cross(a, b, c)) < DEGENERATE_AREA
+            {
+                issues.push(MeshIssue::Degenerate { triangle });
+            }
+        }
+        issues
+    }
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_empty()
+    }
+    pub fn to_2d(&self) -> Vec<[f32; 2]> {
+        self.vertices.iter().map(|v| [v[0], v[1]]).collect()
+    }
+}
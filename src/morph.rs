@@ -0,0 +1,188 @@
+use crate::mesh::Mesh;
+use crate::raster::{rasterize_bounds, Bitmap, RasterOptions};
+use crate::style::GlyphStyle;
+use crate::svg::{fill_color, paint, svg_document, triangles_path, SvgStyle};
+use crate::unit_diacritic::Diacritic;
+use crate::unit_glyph::{double_section_indices, double_section_keys};
+use crate::Numeral;
+use std::f32::consts::PI;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    Smoothstep,
+}
+
+impl Easing {
+    // Maps t in [0, 1] onto [0, 1], fixing both ends
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (2.0 - 2.0 * t).powi(3) / 2.0
+                }
+            }
+            Easing::Smoothstep => t * t * (3.0 - 2.0 * t),
+        }
+    }
+}
+
+// Ta is a fan around a point on its rim while the other diacritics are strips
+// of (upper, lower) pairs running from x = -1 to 1. To blend between them the
+// disc is resampled as a strip of the same length.
+fn diacritic_strip(diacritic: Diacritic, style: &GlyphStyle) -> Mesh {
+    // every strip diacritic shares one sample rate, so any stands in for ta
+    let sample_rate = style.sample_rate(Diacritic::Shey);
+    if diacritic != Diacritic::Ta {
+        return style.diacritic_mesh(diacritic);
+    }
+    let r = (style.diacritic_radius / 2.0).sqrt();
+    let mut v: Vec<[f32; 3]> = Vec::new();
+    for i in 0..=sample_rate {
+        let a = PI * i as f32 / sample_rate as f32;
+        v.push([-r * a.cos(), r * a.sin(), 0.0]);
+        v.push([-r * a.cos(), -r * a.sin(), 0.0]);
+    }
+    let indices = Diacritic::Shey
+        .indices(sample_rate)
+        .unwrap_or_else(|e| panic!("{}", e));
+    Mesh::new(v, indices)
+}
+
+fn lerp(a: &Mesh, b: &Mesh, t: f32) -> Mesh {
+    // zipping would silently drop the tail of the longer mesh
+    assert_eq!(
+        a.vertices.len(),
+        b.vertices.len(),
+        "Morphed meshes need the same vertex count!"
+    );
+    Mesh::new(
+        a.vertices
+            .iter()
+            .zip(&b.vertices)
+            .map(|(p, q)| [0, 1, 2].map(|k| p[k] + (q[k] - p[k]) * t))
+            .collect(),
+        a.indices.clone(),
+    )
+}
+
+fn shrink(section: &Mesh, f: f32) -> Mesh {
+    let n = section.vertices.len() as f32;
+    let c = section
+        .vertices
+        .iter()
+        .fold([0.0, 0.0], |c, p| [c[0] + p[0] / n, c[1] + p[1] / n]);
+    section
+        .translate(-c[0], -c[1])
+        .scale(f, f)
+        .translate(c[0], c[1])
+}
+
+// Sections both glyphs share stay put, the ones only `a` has collapse onto
+// their centers and the ones only `b` has grow out of theirs. The diacritic
+// slides between the two anchors while its curve blends.
+pub fn morph_mesh(a: Numeral, b: Numeral, t: f32, style: &GlyphStyle) -> Mesh {
+    let t = t.clamp(0.0, 1.0);
+    let gv = style.glyph_mesh(a).vertices;
+    let (ka, kb) = (double_section_keys(a), double_section_keys(b));
+    let mut glyph = Mesh::new(gv.clone(), vec![]);
+    for k in ka.iter().chain(kb.iter().filter(|k| !ka.contains(k))) {
+        let idx = double_section_indices(*k).to_vec();
+        match (ka.contains(k), kb.contains(k)) {
+            (true, true) => glyph.indices.extend(idx),
+            (in_a, _) => {
+                let section = Mesh::new(gv.clone(), idx);
+                let section = Mesh::new(section.triangles().flatten().collect(), (0..6).collect());
+                glyph.append(&shrink(&section, if in_a { 1.0 - t } else { t }));
+            }
+        }
+    }
+    let (da, db) = (a.diacritic(), b.diacritic());
+    let (ma, mb) = if da == db {
+        (style.diacritic_mesh(da), style.diacritic_mesh(db))
+    } else {
+        (diacritic_strip(da, style), diacritic_strip(db, style))
+    };
+    let diacritic = lerp(
+        &style.place_diacritic(da, &ma),
+        &style.place_diacritic(db, &mb),
+        t,
+    );
+    glyph.merge(&diacritic)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MorphOptions {
+    // Including both end points
+    pub frames: usize,
+    pub easing: Easing,
+}
+
+impl Default for MorphOptions {
+    fn default() -> MorphOptions {
+        MorphOptions {
+            frames: 12,
+            easing: Easing::EaseInOut,
+        }
+    }
+}
+
+pub fn morph_frames(a: Numeral, b: Numeral, opts: &MorphOptions, style: &GlyphStyle) -> Vec<Mesh> {
+    if opts.frames < 2 {
+        panic!("Need at least two frames!");
+    }
+    (0..opts.frames)
+        .map(|i| {
+            let t = opts.easing.apply(i as f32 / (opts.frames - 1) as f32);
+            morph_mesh(a, b, t, style)
+        })
+        .collect()
+}
+
+// One standalone document per frame, all sharing a view box. The fill
+// switches from `a`'s tier color to `b`'s halfway through.
+pub fn morph_svg_frames(
+    a: Numeral,
+    b: Numeral,
+    opts: &MorphOptions,
+    style: &SvgStyle,
+) -> Vec<String> {
+    let frames = morph_frames(a, b, opts, &style.glyph);
+    let bounds = Mesh::concat(&frames).bounds2d();
+    frames
+        .iter()
+        .enumerate()
+        .map(|(i, m)| {
+            let u = if 2 * i < frames.len() { a } else { b };
+            let body = paint(&triangles_path(m), fill_color(u, style), style);
+            svg_document(bounds, &body, style)
+        })
+        .collect()
+}
+
+// Rasterizes every frame onto a canvas covering all of them, so the images
+// line up when played back.
+pub fn rasterize_frames(frames: &[Mesh], opts: &RasterOptions) -> Vec<Bitmap> {
+    let bounds = Mesh::concat(frames).bounds2d();
+    frames
+        .iter()
+        .map(|m| rasterize_bounds(m, bounds, opts))
+        .collect()
+}
+
+pub fn png_frames(frames: &[Mesh], opts: &RasterOptions) -> Vec<Vec<u8>> {
+    rasterize_frames(frames, opts)
+        .iter()
+        .map(|b| b.to_png())
+        .collect()
+}
@@ -0,0 +1,213 @@
+use crate::extrude::{boundary_edges, weld};
+use crate::mesh::Mesh;
+use crate::style::GlyphStyle;
+use crate::{Diacritic, Numeral};
+use std::collections::HashMap;
+
+// Relative tolerance for dropping a point that sits on the line through its
+// neighbours.
+const COLLINEAR_EPSILON: f32 = 1e-4;
+
+// A closed polygon; the last point connects back to the first. Outer
+// boundaries run counter-clockwise and holes clockwise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contour {
+    pub points: Vec<[f32; 2]>,
+}
+
+impl Contour {
+    pub fn signed_area(&self) -> f32 {
+        let n = self.points.len();
+        (0..n)
+            .map(|i| {
+                let (a, b) = (self.points[i], self.points[(i + 1) % n]);
+                a[0] * b[1] - b[0] * a[1]
+            })
+            .sum::<f32>()
+            / 2.0
+    }
+    pub fn is_hole(&self) -> bool {
+        self.signed_area() < 0.0
+    }
+    // Even-odd test against this contour alone
+    pub fn contains(&self, p: [f32; 2]) -> bool {
+        let n = self.points.len();
+        let mut inside = false;
+        for i in 0..n {
+            let (a, b) = (self.points[i], self.points[(i + n - 1) % n]);
+            if (a[1] > p[1]) != (b[1] > p[1])
+                && p[0] < (b[0] - a[0]) * (p[1] - a[1]) / (b[1] - a[1]) + a[0]
+            {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+    pub fn reversed(&self) -> Contour {
+        Contour {
+            points: self.points.iter().rev().copied().collect(),
+        }
+    }
+    pub fn simplify(&self) -> Contour {
+        let mut pts = self.points.clone();
+        let mut changed = true;
+        while changed && pts.len() > 3 {
+            changed = false;
+            let n = pts.len();
+            for i in 0..n {
+                let (a, p, b) = (pts[(i + n - 1) % n], pts[i], pts[(i + 1) % n]);
+                let u = [p[0] - a[0], p[1] - a[1]];
+                let w = [b[0] - p[0], b[1] - p[1]];
+                let cross = u[0] * w[1] - u[1] * w[0];
+                let dot = u[0] * w[0] + u[1] * w[1];
+                let scale = (u[0].hypot(u[1]) * w[0].hypot(w[1])).max(f32::MIN_POSITIVE);
+                if cross.abs() / scale < COLLINEAR_EPSILON && dot >= 0.0 {
+                    pts.remove(i);
+                    changed = true;
+                    break;
+                }
+            }
+        }
+        Contour { points: pts }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Region {
+    pub outer: Contour,
+    pub holes: Vec<Contour>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Path {
+    pub contours: Vec<Contour>,
+}
+
+impl Path {
+    pub fn area(&self) -> f32 {
+        self.contours.iter().map(|c| c.signed_area()).sum()
+    }
+    pub fn contains(&self, p: [f32; 2]) -> bool {
+        let winding: i32 = self
+            .contours
+            .iter()
+            .filter(|c| c.contains(p))
+            .map(|c| if c.is_hole() { -1 } else { 1 })
+            .sum();
+        winding > 0
+    }
+    // Each hole is assigned to the smallest outer contour around it
+    pub fn regions(&self) -> Vec<Region> {
+        let mut regions: Vec<Region> = self
+            .contours
+            .iter()
+            .filter(|c| !c.is_hole())
+            .map(|c| Region {
+                outer: c.clone(),
+                holes: Vec::new(),
+            })
+            .collect();
+        for hole in self.contours.iter().filter(|c| c.is_hole()) {
+            let owner = regions
+                .iter_mut()
+                .filter(|r| r.outer.contains(hole.points[0]))
+                .min_by(|a, b| a.outer.signed_area().total_cmp(&b.outer.signed_area()));
+            if let Some(r) = owner {
+                r.holes.push(hole.clone())
+            }
+        }
+        regions
+    }
+    pub fn translate(&self, dx: f32, dy: f32) -> Path {
+        Path {
+            contours: self
+                .contours
+                .iter()
+                .map(|c| Contour {
+                    points: c.points.iter().map(|p| [p[0] + dx, p[1] + dy]).collect(),
+                })
+                .collect(),
+        }
+    }
+    // SVG path data with y flipped into SVG's downward axis
+    pub fn to_svg_d(&self) -> String {
+        let mut d = String::new();
+        for c in &self.contours {
+            for (i, p) in c.points.iter().enumerate() {
+                d += &format!("{}{:.4} {:.4}", if i == 0 { "M" } else { "L" }, p[0], -p[1]);
+            }
+            d += "Z";
+        }
+        d
+    }
+}
+
+fn turn(from: [f32; 3], at: [f32; 3], to: [f32; 3]) -> f32 {
+    let u = [at[0] - from[0], at[1] - from[1]];
+    let w = [to[0] - at[0], to[1] - at[1]];
+    (u[0] * w[1] - u[1] * w[0]).atan2(u[0] * w[0] + u[1] * w[1])
+}
+
+// Unions the triangles of a flat mesh and walks its boundary into contours.
+pub fn outline(mesh: &Mesh) -> Path {
+    let Mesh {
+        vertices: v,
+        indices: tris,
+    } = weld(mesh);
+    let mut next: HashMap<u32, Vec<u32>> = HashMap::new();
+    let mut edges = boundary_edges(&tris);
+    edges.sort_unstable();
+    for [a, b] in &edges {
+        next.entry(*a).or_default().push(*b);
+    }
+    let mut contours: Vec<Contour> = Vec::new();
+    for [start, first] in edges {
+        let Some(out) = next.get_mut(&start) else {
+            continue;
+        };
+        let Some(k) = out.iter().position(|&b| b == first) else {
+            continue;
+        };
+        out.remove(k);
+        let mut loop_: Vec<u32> = vec![start];
+        let (mut prev, mut cur) = (start, first);
+        while cur != start {
+            loop_.push(cur);
+            let Some(out) = next.get_mut(&cur) else { break };
+            if out.is_empty() {
+                break;
+            }
+            // at pinch points keep the tightest left turn so loops stay simple
+            let (k, _) = out
+                .iter()
+                .enumerate()
+                .map(|(k, &b)| (k, turn(v[prev as usize], v[cur as usize], v[b as usize])))
+                .fold((0, f32::MIN), |best, c| if c.1 > best.1 { c } else { best });
+            let n = out.remove(k);
+            prev = cur;
+            cur = n;
+        }
+        if loop_.len() >= 3 {
+            let c = Contour {
+                points: loop_
+                    .iter()
+                    .map(|&i| [v[i as usize][0], v[i as usize][1]])
+                    .collect(),
+            };
+            contours.push(c.simplify());
+        }
+    }
+    Path { contours }
+}
+
+pub fn glyph_outline(u: Numeral, style: &GlyphStyle) -> Path {
+    outline(&style.glyph_mesh(u))
+}
+
+pub fn diacritic_outline(diacritic: Diacritic, style: &GlyphStyle) -> Path {
+    outline(&style.diacritic_mesh(diacritic))
+}
+
+pub fn numeral_outline(u: Numeral, style: &GlyphStyle) -> Path {
+    outline(&style.numeral_mesh(u))
+}
@@ -0,0 +1,122 @@
+// Single page PDF documents holding one numeral as vector paths. Written by
+// hand since the file only needs four objects and an uncompressed stream.
+use crate::geom::{point_bounds, xy};
+use crate::mesh::Mesh;
+use crate::style::GlyphStyle;
+use crate::Numeral;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PdfOptions {
+    // Points per glyph unit
+    pub scale: f32,
+    // Points of blank page around the numeral
+    pub padding: f32,
+    pub fill: [u8; 3],
+    // Draws the diacritic as cubic Beziers within this distance of the exact
+    // curve; the sampled mesh is used when None
+    pub curve_tolerance: Option<f32>,
+}
+
+impl Default for PdfOptions {
+    fn default() -> PdfOptions {
+        PdfOptions {
+            scale: 72.0,
+            padding: 8.0,
+            fill: [0, 0, 0],
+            curve_tolerance: Some(1e-3),
+        }
+    }
+}
+
+// Triangles all wind the same way so the nonzero fill covers their union
+fn triangles_ops(mesh: &Mesh) -> String {
+    let mut s = String::new();
+    for [a, b, c] in mesh.wind_ccw().triangles() {
+        s += &format!(
+            "{:.4} {:.4} m {:.4} {:.4} l {:.4} {:.4} l h\n",
+            a[0], a[1], b[0], b[1], c[0], c[1]
+        );
+    }
+    s
+}
+
+pub fn numeral_pdf(u: Numeral, style: &GlyphStyle, opts: &PdfOptions) -> Vec<u8> {
+    let glyph = style.glyph_mesh(u);
+    let d = u.diacritic();
+    let mut points: Vec<[f32; 2]> = glyph.vertices.iter().copied().map(xy).collect();
+    let mut ops = triangles_ops(&glyph);
+    match opts.curve_tolerance {
+        Some(tolerance) => {
+            let curve = style
+                .diacritic_curve(d)
+                .beziers(tolerance)
+                .transform(style.diacritic_transform(d));
+            if let Some(first) = curve.segments.first() {
+                ops += &format!("{:.4} {:.4} m\n", first[0][0], first[0][1]);
+            }
+            for c in &curve.segments {
+                ops += &format!(
+                    "{:.4} {:.4} {:.4} {:.4} {:.4} {:.4} c\n",
+                    c[1][0], c[1][1], c[2][0], c[2][1], c[3][0], c[3][1]
+                );
+                // a cubic stays inside the hull of its control points
+                points.extend(c);
+            }
+            ops += "h\n";
+        }
+        None => {
+            let diacritic = style.place_diacritic(d, &style.diacritic_mesh(d));
+            points.extend(diacritic.vertices.iter().copied().map(xy));
+            ops += &triangles_ops(&diacritic);
+        }
+    }
+    let [x0, y0, x1, y1] = point_bounds(points).unwrap_or_default();
+    let (s, pad) = (opts.scale, opts.padding);
+    let width = (x1 - x0) * s + 2.0 * pad;
+    let height = (y1 - y0) * s + 2.0 * pad;
+    let [r, g, b] = opts.fill.map(|c| c as f32 / 255.0);
+    let content = format!(
+        "{:.4} {:.4} {:.4} rg\n{} 0 0 {} {:.4} {:.4} cm\n{}f\n",
+        r,
+        g,
+        b,
+        s,
+        s,
+        pad - x0 * s,
+        pad - y0 * s,
+        ops
+    );
+
+    let objects = [
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+        format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {:.2} {:.2}] /Resources << >> /Contents 4 0 R >>",
+            width, height
+        ),
+        format!(
+            "<< /Length {} >>\nstream\n{}endstream",
+            content.len(),
+            content
+        ),
+    ];
+    let mut pdf: Vec<u8> = b"%PDF-1.4\n".to_vec();
+    let mut offsets: Vec<usize> = Vec::new();
+    for (i, o) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend(format!("{} 0 obj\n{}\nendobj\n", i + 1, o).bytes());
+    }
+    let xref = pdf.len();
+    // every entry is exactly 20 bytes, line end included
+    let mut table = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+    for o in offsets {
+        table += &format!("{:010} 00000 n \n", o);
+    }
+    table += &format!(
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+        objects.len() + 1,
+        xref
+    );
+    pdf.extend(table.bytes());
+    pdf
+}
@@ -0,0 +1,163 @@
+// Point queries against composed numerals, for finding what sits under a
+// pointer. Coordinates are the ones `numeral_mesh` and `typeset_mesh` use.
+use crate::geom::{cross2d, point_bounds, segment_distance, xy};
+use crate::mesh::Mesh;
+use crate::style::GlyphStyle;
+use crate::typeset::{layout, LayoutOptions, Placement};
+use crate::unit_glyph::{double_section_indices, double_section_keys};
+use crate::{Numeral, Uint};
+
+// Both mirrored halves of a section report the key `section_keys` gives it
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Part {
+    Section(usize),
+    Diacritic,
+}
+
+// `index` is the digit's position within a `Uint`, 0 for a lone numeral
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Hit {
+    pub numeral: Numeral,
+    pub index: usize,
+    pub part: Part,
+}
+
+// Edges count as inside, whichever way the triangle winds
+fn in_triangle(p: [f32; 2], t: [[f32; 3]; 3]) -> bool {
+    let [a, b, c] = t.map(xy);
+    let d = [cross2d(a, b, p), cross2d(b, c, p), cross2d(c, a, p)];
+    d.iter().all(|&x| x >= 0.0) || d.iter().all(|&x| x <= 0.0)
+}
+
+// Zero inside the mesh, otherwise the distance to its closest edge
+fn distance(mesh: &Mesh, p: [f32; 2]) -> f32 {
+    mesh.triangles()
+        .map(|t| {
+            if in_triangle(p, t) {
+                return 0.0;
+            }
+            (0..3)
+                .map(|i| segment_distance(p, xy(t[i]), xy(t[(i + 1) % 3])))
+                .fold(f32::MAX, f32::min)
+        })
+        .fold(f32::MAX, f32::min)
+}
+
+// Bounds of the vertices the triangles use. The glyph meshes carry all 40
+// vertices whichever sections are drawn, so `Mesh::bounds2d` spans the full
+// cell `typeset::digit_span` lays out rather than the ink.
+pub fn tight_bounds(mesh: &Mesh) -> Option<[f32; 4]> {
+    point_bounds(mesh.triangles().flatten().map(xy))
+}
+
+// The composed numeral split into the pieces a hit can report
+pub fn numeral_parts(u: Numeral, style: &GlyphStyle) -> Vec<(Part, Mesh)> {
+    let glyph = style.glyph_mesh(u);
+    let mut parts: Vec<(Part, Mesh)> = Vec::new();
+    for k in double_section_keys(u) {
+        let part = Part::Section(k % 11);
+        let indices = double_section_indices(k).to_vec();
+        match parts.iter_mut().find(|(p, _)| *p == part) {
+            Some((_, m)) => m.indices.extend(indices),
+            None => parts.push((part, Mesh::new(glyph.vertices.clone(), indices))),
+        }
+    }
+    let d = u.diacritic();
+    parts.push((
+        Part::Diacritic,
+        style.place_diacritic(d, &style.diacritic_mesh(d)),
+    ));
+    parts
+}
+
+pub fn numeral_bounds(u: Numeral, style: &GlyphStyle) -> [f32; 4] {
+    tight_bounds(&style.numeral_mesh(u)).unwrap_or_default()
+}
+
+pub fn hit_test(u: Numeral, style: &GlyphStyle, p: [f32; 2]) -> Option<Hit> {
+    numeral_parts(u, style)
+        .into_iter()
+        .find(|(_, m)| m.triangles().any(|t| in_triangle(p, t)))
+        .map(|(part, _)| Hit {
+            numeral: u,
+            index: 0,
+            part,
+        })
+}
+
+struct Target {
+    placement: Placement,
+    parts: Vec<(Part, Mesh)>,
+    bounds: [f32; 4],
+}
+
+// The laid out digits of a `Uint` with their geometry built once, so a
+// pointer can be tested on every move without redoing the layout.
+pub struct Picker {
+    targets: Vec<Target>,
+}
+
+impl Picker {
+    pub fn new(u: &Uint, opts: &LayoutOptions, style: &GlyphStyle) -> Picker {
+        let targets = layout(u, opts, style)
+            .glyphs
+            .into_iter()
+            .map(|placement| {
+                let [ox, oy] = placement.origin;
+                let parts: Vec<(Part, Mesh)> = numeral_parts(placement.numeral, style)
+                    .into_iter()
+                    .map(|(part, m)| (part, m.translate(ox, oy)))
+                    .collect();
+                let [x0, y0, x1, y1] = numeral_bounds(placement.numeral, style);
+                Target {
+                    placement,
+                    parts,
+                    bounds: [x0 + ox, y0 + oy, x1 + ox, y1 + oy],
+                }
+            })
+            .collect();
+        Picker { targets }
+    }
+    pub fn placements(&self) -> Vec<Placement> {
+        self.targets.iter().map(|t| t.placement).collect()
+    }
+    // Tight bounds of each digit, in layout order
+    pub fn bounds(&self) -> Vec<[f32; 4]> {
+        self.targets.iter().map(|t| t.bounds).collect()
+    }
+    pub fn hit(&self, p: [f32; 2]) -> Option<Hit> {
+        self.targets
+            .iter()
+            .filter(|t| {
+                let [x0, y0, x1, y1] = t.bounds;
+                p[0] >= x0 && p[0] <= x1 && p[1] >= y0 && p[1] <= y1
+            })
+            .find_map(|t| {
+                t.parts
+                    .iter()
+                    .find(|(_, m)| m.triangles().any(|tri| in_triangle(p, tri)))
+                    .map(|(part, _)| Hit {
+                        numeral: t.placement.numeral,
+                        index: t.placement.index,
+                        part: *part,
+                    })
+            })
+    }
+    // The closest part of any digit and how far away it is, zero when the
+    // point is on it
+    pub fn nearest(&self, p: [f32; 2]) -> Option<(Hit, f32)> {
+        self.targets
+            .iter()
+            .flat_map(|t| {
+                t.parts.iter().map(move |(part, m)| {
+                    let hit = Hit {
+                        numeral: t.placement.numeral,
+                        index: t.placement.index,
+                        part: *part,
+                    };
+                    (hit, distance(m, p))
+                })
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+    }
+}
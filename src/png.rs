@@ -0,0 +1,200 @@
+// A small self-contained PNG writer: 8-bit RGBA or grayscale, compressed
+// with fixed-Huffman deflate and a single-candidate LZ77 matcher.
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+    for b in bytes {
+        crc ^= *b as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                0xedb8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in bytes.chunks(5552) {
+        for x in chunk {
+            a += *x as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    (b << 16) | a
+}
+
+struct BitWriter {
+    bytes: Vec<u8>,
+    acc: u64,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn new() -> BitWriter {
+        BitWriter {
+            bytes: Vec::new(),
+            acc: 0,
+            bits: 0,
+        }
+    }
+    // Writes `n` bits of `v`, least significant first
+    fn bits(&mut self, v: u32, n: u32) {
+        self.acc |= (v as u64) << self.bits;
+        self.bits += n;
+        while self.bits >= 8 {
+            self.bytes.push(self.acc as u8);
+            self.acc >>= 8;
+            self.bits -= 8;
+        }
+    }
+    // Huffman codes are packed most significant bit first
+    fn code(&mut self, code: u32, n: u32) {
+        self.bits(code.reverse_bits() >> (32 - n), n)
+    }
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.acc as u8);
+        }
+        self.bytes
+    }
+}
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DIST_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DIST_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+fn literal(w: &mut BitWriter, v: u32) {
+    match v {
+        0..=143 => w.code(0x30 + v, 8),
+        144..=255 => w.code(0x190 + v - 144, 9),
+        256..=279 => w.code(v - 256, 7),
+        _ => w.code(0xc0 + v - 280, 8),
+    }
+}
+
+fn table_index(base: &[u16], v: usize) -> usize {
+    base.iter().rposition(|&b| b as usize <= v).unwrap_or(0)
+}
+
+fn deflate(data: &[u8]) -> Vec<u8> {
+    const WINDOW: usize = 32768;
+    const MAX_MATCH: usize = 258;
+    let mut w = BitWriter::new();
+    // final block, fixed Huffman codes
+    w.bits(1, 1);
+    w.bits(1, 2);
+    let mut head: Vec<usize> = vec![usize::MAX; 1 << 15];
+    let hash = |i: usize| {
+        (((data[i] as usize) << 10) ^ ((data[i + 1] as usize) << 5) ^ data[i + 2] as usize) & 0x7fff
+    };
+    let mut i = 0;
+    while i < data.len() {
+        let mut best = 0;
+        let mut dist = 0;
+        if i + 3 <= data.len() {
+            let h = hash(i);
+            let candidate = head[h];
+            head[h] = i;
+            if candidate != usize::MAX && i - candidate <= WINDOW {
+                let max = MAX_MATCH.min(data.len() - i);
+                let mut n = 0;
+                while n < max && data[candidate + n] == data[i + n] {
+                    n += 1;
+                }
+                if n >= 3 {
+                    best = n;
+                    dist = i - candidate;
+                }
+            }
+        }
+        if best == 0 {
+            literal(&mut w, data[i] as u32);
+            i += 1;
+            continue;
+        }
+        let li = table_index(&LENGTH_BASE, best);
+        literal(&mut w, 257 + li as u32);
+        w.bits(
+            (best - LENGTH_BASE[li] as usize) as u32,
+            LENGTH_EXTRA[li] as u32,
+        );
+        let di = table_index(&DIST_BASE, dist);
+        w.code(di as u32, 5);
+        w.bits(
+            (dist - DIST_BASE[di] as usize) as u32,
+            DIST_EXTRA[di] as u32,
+        );
+        for j in i + 1..(i + best).min(data.len().saturating_sub(2)) {
+            head[hash(j)] = j;
+        }
+        i += best;
+    }
+    literal(&mut w, 256);
+    w.finish()
+}
+
+pub(crate) fn zlib(data: &[u8]) -> Vec<u8> {
+    let mut z = vec![0x78, 0x01];
+    z.extend(deflate(data));
+    z.extend(adler32(data).to_be_bytes());
+    z
+}
+
+fn chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+// `channels` is 1 for grayscale, 3 for RGB or 4 for RGBA, 8 bits each.
+pub fn encode_png(width: usize, height: usize, channels: usize, pixels: &[u8]) -> Vec<u8> {
+    let color_type: u8 = match channels {
+        1 => 0,
+        3 => 2,
+        4 => 6,
+        _ => panic!("Can only encode 1, 3 or 4 channels"),
+    };
+    // the format has no empty images
+    if width == 0 || height == 0 {
+        panic!("A PNG needs at least one row and one column!")
+    }
+    if pixels.len() != width * height * channels {
+        panic!("Pixel buffer does not match the image size!")
+    }
+    let mut raw: Vec<u8> = Vec::with_capacity((width * channels + 1) * height);
+    for row in pixels.chunks(width * channels) {
+        // filter type 0, rows are stored as is
+        raw.push(0);
+        raw.extend(row);
+    }
+    let mut ihdr: Vec<u8> = Vec::new();
+    ihdr.extend((width as u32).to_be_bytes());
+    ihdr.extend((height as u32).to_be_bytes());
+    ihdr.extend([8, color_type, 0, 0, 0]);
+    let mut png: Vec<u8> = b"\x89PNG\r\n\x1a\n".to_vec();
+    chunk(&mut png, b"IHDR", &ihdr);
+    chunk(&mut png, b"IDAT", &zlib(&raw));
+    chunk(&mut png, b"IEND", &[]);
+    png
+}
@@ -0,0 +1,185 @@
+use crate::geom::netpbm;
+use crate::mesh::Mesh;
+use crate::png::encode_png;
+use crate::style::GlyphStyle;
+use crate::typeset::{typeset_mesh, LayoutOptions};
+use crate::{Numeral, Uint};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bitmap {
+    pub width: usize,
+    pub height: usize,
+    // RGBA, row by row from the top
+    pub pixels: Vec<[u8; 4]>,
+}
+
+impl Bitmap {
+    pub fn new(width: usize, height: usize, color: [u8; 4]) -> Bitmap {
+        Bitmap {
+            width,
+            height,
+            pixels: vec![color; width * height],
+        }
+    }
+    pub fn get(&self, x: usize, y: usize) -> [u8; 4] {
+        self.pixels[y * self.width + x]
+    }
+    // Composites `color` over the pixel with the given extra opacity
+    pub fn blend(&mut self, x: usize, y: usize, color: [u8; 4], coverage: f32) {
+        let p = &mut self.pixels[y * self.width + x];
+        let sa = color[3] as f32 / 255.0 * coverage.clamp(0.0, 1.0);
+        let da = p[3] as f32 / 255.0;
+        let oa = sa + da * (1.0 - sa);
+        if oa <= 0.0 {
+            return;
+        }
+        for k in 0..3 {
+            let c = (color[k] as f32 * sa + p[k] as f32 * da * (1.0 - sa)) / oa;
+            p[k] = c.round() as u8;
+        }
+        p[3] = (oa * 255.0).round() as u8;
+    }
+    // Flattens onto white, so transparent areas read as paper
+    pub fn rgb(&self) -> Vec<[u8; 3]> {
+        self.pixels
+            .iter()
+            .map(|p| {
+                let a = p[3] as f32 / 255.0;
+                [0, 1, 2].map(|k| (p[k] as f32 * a + 255.0 * (1.0 - a)).round() as u8)
+            })
+            .collect()
+    }
+    pub fn luma(&self) -> Vec<u8> {
+        self.rgb()
+            .iter()
+            .map(|p| {
+                (0.299 * p[0] as f32 + 0.587 * p[1] as f32 + 0.114 * p[2] as f32).round() as u8
+            })
+            .collect()
+    }
+    pub fn to_png(&self) -> Vec<u8> {
+        encode_png(self.width, self.height, 4, &self.pixels.concat())
+    }
+    pub fn to_pgm(&self) -> Vec<u8> {
+        netpbm("P5", self.width, self.height, &self.luma())
+    }
+    pub fn to_ppm(&self) -> Vec<u8> {
+        netpbm("P6", self.width, self.height, &self.rgb().concat())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RasterOptions {
+    // Pixels per glyph unit
+    pub scale: f32,
+    pub padding: usize,
+    pub foreground: [u8; 4],
+    // Use an alpha of 0 for a transparent background
+    pub background: [u8; 4],
+    // Samples per pixel along each axis; 1 turns anti-aliasing off
+    pub samples: usize,
+}
+
+impl Default for RasterOptions {
+    fn default() -> RasterOptions {
+        RasterOptions {
+            scale: 64.0,
+            padding: 4,
+            foreground: [0, 0, 0, 255],
+            background: [255, 255, 255, 255],
+            samples: 4,
+        }
+    }
+}
+
+// Fraction of each pixel covered by the union of the triangles. `origin` is
+// the glyph-space point at the top left corner of the image.
+pub fn coverage(
+    mesh: &Mesh,
+    width: usize,
+    height: usize,
+    origin: [f32; 2],
+    scale: f32,
+    samples: usize,
+) -> Vec<f32> {
+    let s = samples.max(1);
+    let (sw, sh) = (width * s, height * s);
+    let mut mask = vec![false; sw * sh];
+    let sub = scale * s as f32;
+    let to_sub = |p: [f32; 3]| [(p[0] - origin[0]) * sub, (origin[1] - p[1]) * sub];
+    for t in mesh.triangles() {
+        let [a, b, c] = t.map(to_sub);
+        let area = (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]);
+        if area == 0.0 {
+            continue;
+        }
+        let x0 = a[0].min(b[0]).min(c[0]).floor().max(0.0) as usize;
+        let y0 = a[1].min(b[1]).min(c[1]).floor().max(0.0) as usize;
+        let x1 = (a[0].max(b[0]).max(c[0]).ceil().max(0.0) as usize).min(sw);
+        let y1 = (a[1].max(b[1]).max(c[1]).ceil().max(0.0) as usize).min(sh);
+        for y in y0..y1 {
+            for x in x0..x1 {
+                let p = [x as f32 + 0.5, y as f32 + 0.5];
+                let edge = |q: [f32; 2], r: [f32; 2]| {
+                    ((r[0] - q[0]) * (p[1] - q[1]) - (r[1] - q[1]) * (p[0] - q[0])) * area.signum()
+                };
+                if edge(a, b) >= 0.0 && edge(b, c) >= 0.0 && edge(c, a) >= 0.0 {
+                    mask[y * sw + x] = true;
+                }
+            }
+        }
+    }
+    let mut cov = vec![0.0; width * height];
+    for y in 0..sh {
+        for x in 0..sw {
+            if mask[y * sw + x] {
+                cov[(y / s) * width + x / s] += 1.0;
+            }
+        }
+    }
+    let n = (s * s) as f32;
+    cov.iter().map(|c| c / n).collect()
+}
+
+pub fn rasterize(mesh: &Mesh, opts: &RasterOptions) -> Bitmap {
+    rasterize_bounds(mesh, mesh.bounds2d(), opts)
+}
+
+// Like `rasterize` but sizes the image to `bounds` (x0, y0, x1, y1) rather
+// than the mesh, so a sequence of frames can share one canvas.
+pub fn rasterize_bounds(mesh: &Mesh, bounds: [f32; 4], opts: &RasterOptions) -> Bitmap {
+    let [x0, y0, x1, y1] = bounds;
+    let pad = opts.padding as f32 / opts.scale;
+    // an empty mesh without padding still gets a single pixel of background
+    let width = (((x1 - x0) * opts.scale).ceil() as usize + 2 * opts.padding).max(1);
+    let height = (((y1 - y0) * opts.scale).ceil() as usize + 2 * opts.padding).max(1);
+    let cov = coverage(
+        mesh,
+        width,
+        height,
+        [x0 - pad, y1 + pad],
+        opts.scale,
+        opts.samples,
+    );
+    let mut bmp = Bitmap::new(width, height, opts.background);
+    for (i, c) in cov.iter().enumerate() {
+        if *c > 0.0 {
+            bmp.blend(i % width, i / width, opts.foreground, *c);
+        }
+    }
+    bmp
+}
+
+pub fn rasterize_numeral(u: Numeral, style: &GlyphStyle, opts: &RasterOptions) -> Bitmap {
+    rasterize(&style.numeral_mesh(u), opts)
+}
+
+pub fn rasterize_uint(
+    u: &Uint,
+    layout: &LayoutOptions,
+    style: &GlyphStyle,
+    opts: &RasterOptions,
+) -> Bitmap {
+    let mesh = typeset_mesh(u, layout, style);
+    rasterize(&mesh, opts)
+}
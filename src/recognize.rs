@@ -0,0 +1,320 @@
+// Offline recognition of drawn numerals by template matching. Every numeral
+// is one of twelve glyph bodies (the sections `section_keys` picks by row and
+// column) under one of five diacritics, so a drawing is split at the gap
+// between the two and each half is matched on its own against templates
+// rendered from the library's geometry. A part scores by how much of its ink
+// lies near the template's and how much of the template's lies near its own,
+// so a missing or extra section costs as much as a misplaced one. A
+// candidate's confidence is the product of its body and diacritic scores.
+use crate::geom::{point_bounds, segment_distance, union};
+use crate::mesh::Mesh;
+use crate::pick::tight_bounds;
+use crate::raster::{coverage, Bitmap};
+use crate::style::GlyphStyle;
+use crate::{Diacritic, Numeral};
+
+// Cells along each side of the grid strokes are drawn into before splitting
+const INK_GRID: usize = 128;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RecognizerOptions {
+    // Cells along each side of the comparison grids
+    pub grid: usize,
+    // How many cells ink may stray from the template and still count as
+    // matching
+    pub slack: usize,
+    // Pen width as a fraction of the drawing's larger side
+    pub stroke_width: f32,
+}
+
+impl Default for RecognizerOptions {
+    fn default() -> RecognizerOptions {
+        RecognizerOptions {
+            grid: 24,
+            slack: 1,
+            stroke_width: 0.02,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Candidate {
+    pub numeral: Numeral,
+    // From 0 to 1
+    pub confidence: f32,
+}
+
+// Coverage on a grid of cells, row by row from the top
+struct Ink {
+    width: usize,
+    height: usize,
+    cells: Vec<f32>,
+}
+
+impl Ink {
+    fn get(&self, x: usize, y: usize) -> f32 {
+        self.cells[y * self.width + x]
+    }
+    // Bounds of the inked cells within rows y0..y1, as [x0, y0, x1, y1] in
+    // cell units with y growing downward
+    fn bounds(&self, y0: usize, y1: usize) -> Option<[f32; 4]> {
+        union((y0..y1).flat_map(|y| {
+            (0..self.width)
+                .filter(move |&x| self.get(x, y) > 0.5)
+                .map(move |x| [x as f32, y as f32, x as f32 + 1.0, y as f32 + 1.0])
+        }))
+    }
+    fn row_inked(&self, y: usize) -> bool {
+        (0..self.width).any(|x| self.get(x, y) > 0.5)
+    }
+}
+
+// How a part is fitted into its comparison grid. Bodies keep their height
+// fixed so narrow ones stay narrow; diacritics fit their larger side.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Fit {
+    Height,
+    Square,
+}
+
+// Origin and cells per unit that fit `bounds` into the grid, centered, with
+// a cell free on every side. `bounds` has y growing upward.
+fn fit(bounds: [f32; 4], grid: usize, mode: Fit) -> ([f32; 2], f32) {
+    let [x0, y0, x1, y1] = bounds;
+    let side = match mode {
+        Fit::Height => y1 - y0,
+        Fit::Square => (x1 - x0).max(y1 - y0),
+    };
+    let scale = (grid as f32 - 2.0) / side.max(f32::EPSILON);
+    let half = grid as f32 / 2.0 / scale;
+    let (cx, cy) = ((x0 + x1) / 2.0, (y0 + y1) / 2.0);
+    ([cx - half, cy + half], scale)
+}
+
+// Each cell takes the largest value within `radius` cells along either axis
+fn dilate(cells: &[f32], grid: usize, radius: usize) -> Vec<f32> {
+    let r = radius as isize;
+    let g = grid as isize;
+    let mut out = cells.to_vec();
+    for (dx, dy) in [(1, 0), (0, 1)] {
+        let src = out.clone();
+        for y in 0..g {
+            for x in 0..g {
+                let mut m: f32 = 0.0;
+                for k in -r..=r {
+                    let (sx, sy) = (x + k * dx, y + k * dy);
+                    if sx >= 0 && sx < g && sy >= 0 && sy < g {
+                        m = m.max(src[(sy * g + sx) as usize]);
+                    }
+                }
+                out[(y * g + x) as usize] = m;
+            }
+        }
+    }
+    out
+}
+
+// A part on the comparison grid, kept with its dilation
+struct Part {
+    cells: Vec<f32>,
+    dilated: Vec<f32>,
+}
+
+impl Part {
+    fn new(cells: Vec<f32>, grid: usize, slack: usize) -> Part {
+        let dilated = dilate(&cells, grid, slack);
+        Part { cells, dilated }
+    }
+    // Share of this part's ink that lands near `other`'s, from 0 to 1
+    fn covered_by(&self, other: &Part) -> f32 {
+        let total: f32 = self.cells.iter().sum();
+        if total == 0.0 {
+            return 0.0;
+        }
+        let near: f32 = self
+            .cells
+            .iter()
+            .zip(&other.dilated)
+            .map(|(a, b)| a * b.min(1.0))
+            .sum();
+        near / total
+    }
+    fn score(&self, template: &Part) -> f32 {
+        self.covered_by(template) * template.covered_by(self)
+    }
+}
+
+pub struct Recognizer {
+    opts: RecognizerOptions,
+    flip: bool,
+    // Indexed by `duodecimal_index` and `diacritic_index`
+    bodies: Vec<Part>,
+    diacritics: Vec<Part>,
+    // Share of the numeral's height the body takes, for drawings with no
+    // visible gap
+    body_share: f32,
+}
+
+impl Recognizer {
+    pub fn new(style: &GlyphStyle, opts: RecognizerOptions) -> Recognizer {
+        let template = |mesh: &Mesh, mode: Fit| {
+            let bounds = tight_bounds(mesh).unwrap_or_default();
+            let (origin, scale) = fit(bounds, opts.grid, mode);
+            let cells = coverage(mesh, opts.grid, opts.grid, origin, scale, 2);
+            Part::new(cells, opts.grid, opts.slack)
+        };
+        let bodies = (0..12)
+            .map(|i| template(&style.glyph_mesh(Numeral::new(i)), Fit::Height))
+            .collect();
+        let diacritics = Diacritic::ALL
+            .iter()
+            .map(|d| template(&style.diacritic_mesh(*d), Fit::Square))
+            .collect();
+        let body_share = (0..5)
+            .map(|d| {
+                let [_, y0, _, y1] = tight_bounds(&style.numeral_mesh(Numeral::new(12 * d)))
+                    .unwrap_or([0.0, 0.0, 0.0, 1.0]);
+                style.height() / (y1 - y0)
+            })
+            .sum::<f32>()
+            / 5.0;
+        Recognizer {
+            opts,
+            flip: style.flip,
+            bodies,
+            diacritics,
+            body_share,
+        }
+    }
+
+    // Resamples part of the ink onto the comparison grid
+    fn part(&self, ink: &Ink, bounds: [f32; 4], mode: Fit) -> Part {
+        let g = self.opts.grid;
+        // flip to y up for `fit`, then sample back in cell coordinates
+        let [x0, y0, x1, y1] = bounds;
+        let (origin, scale) = fit([x0, -y1, x1, -y0], g, mode);
+        let n = 3;
+        let mut cells = vec![0.0; g * g];
+        for (i, c) in cells.iter_mut().enumerate() {
+            let mut sum = 0.0;
+            for k in 0..n * n {
+                let sx = ((i % g) as f32 + ((k % n) as f32 + 0.5) / n as f32) / scale;
+                let sy = ((i / g) as f32 + ((k / n) as f32 + 0.5) / n as f32) / scale;
+                let (x, y) = (origin[0] + sx, -(origin[1] - sy));
+                // only the part's own rows count, so the other part stays out
+                if x >= 0.0 && x < ink.width as f32 && y >= y0 && y < y1 {
+                    sum += ink.get(x as usize, y as usize);
+                }
+            }
+            *c = sum / (n * n) as f32;
+        }
+        Part::new(cells, g, self.opts.slack)
+    }
+
+    fn rank(&self, ink: &Ink) -> Vec<Candidate> {
+        let Some([_, top, _, bottom]) = ink.bounds(0, ink.height) else {
+            return vec![];
+        };
+        let (top, bottom) = (top as usize, bottom as usize);
+        // the widest run of empty rows separates body from diacritic
+        let mut gap: Option<(usize, usize)> = None;
+        let mut y = top;
+        while y < bottom {
+            if ink.row_inked(y) {
+                y += 1;
+                continue;
+            }
+            let start = y;
+            while !ink.row_inked(y) {
+                y += 1;
+            }
+            if gap.is_none_or(|(a, b)| y - start > b - a) {
+                gap = Some((start, y));
+            }
+        }
+        let (upper_end, lower_start) = gap.unwrap_or_else(|| {
+            let h = (bottom - top) as f32;
+            let share = if self.flip {
+                self.body_share
+            } else {
+                1.0 - self.body_share
+            };
+            let split = top + (share * h).round() as usize;
+            (split, split)
+        });
+        let (body_rows, diacritic_rows) = if self.flip {
+            ((top, upper_end), (lower_start, bottom))
+        } else {
+            ((lower_start, bottom), (top, upper_end))
+        };
+        let score =
+            |(y0, y1): (usize, usize), mode: Fit, templates: &[Part]| match ink.bounds(y0, y1) {
+                Some(b) => {
+                    let part = self.part(ink, b, mode);
+                    templates.iter().map(|t| part.score(t)).collect()
+                }
+                None => vec![0.0; templates.len()],
+            };
+        let body: Vec<f32> = score(body_rows, Fit::Height, &self.bodies);
+        let diacritic: Vec<f32> = score(diacritic_rows, Fit::Square, &self.diacritics);
+        let mut candidates: Vec<Candidate> = (0..60)
+            .map(|i| Candidate {
+                numeral: Numeral::new(i as u8),
+                confidence: body[i % 12] * diacritic[i / 12],
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        candidates
+    }
+
+    // Polylines in glyph orientation, y pointing up; flip screen
+    // coordinates first. Returns every numeral, best match first, or
+    // nothing for an empty drawing.
+    pub fn recognize_strokes(&self, strokes: &[Vec<[f32; 2]>]) -> Vec<Candidate> {
+        let Some([x0, y0, x1, y1]) = point_bounds(strokes.iter().flatten().copied()) else {
+            return vec![];
+        };
+        let half = self.opts.stroke_width * (x1 - x0).max(y1 - y0) / 2.0;
+        let bounds = [x0 - half, y0 - half, x1 + half, y1 + half];
+        let g = INK_GRID;
+        let (origin, scale) = fit(bounds, g, Fit::Square);
+        // never thinner than a cell, so strokes stay connected
+        let reach = half.max(0.5 / scale);
+        let mut cells = vec![0.0; g * g];
+        for (i, c) in cells.iter_mut().enumerate() {
+            let p = [
+                origin[0] + ((i % g) as f32 + 0.5) / scale,
+                origin[1] - ((i / g) as f32 + 0.5) / scale,
+            ];
+            let near = strokes
+                .iter()
+                .flat_map(|s| {
+                    // a single point is a dot
+                    let last = s.len().saturating_sub(1);
+                    (0..s.len()).map(move |k| segment_distance(p, s[k], s[(k + 1).min(last)]))
+                })
+                .fold(f32::MAX, f32::min);
+            if near <= reach {
+                *c = 1.0;
+            }
+        }
+        self.rank(&Ink {
+            width: g,
+            height: g,
+            cells,
+        })
+    }
+
+    // Dark pixels on a light or transparent background count as ink
+    pub fn recognize_bitmap(&self, bitmap: &Bitmap) -> Vec<Candidate> {
+        self.rank(&Ink {
+            width: bitmap.width,
+            height: bitmap.height,
+            cells: bitmap
+                .luma()
+                .iter()
+                .map(|l| 1.0 - *l as f32 / 255.0)
+                .collect(),
+        })
+    }
+}
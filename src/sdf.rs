@@ -0,0 +1,203 @@
+#[cfg(feature = "serde")]
+use crate::atlas::glyph_map;
+use crate::atlas::{glyphs_json, AtlasGlyph, Packer};
+use crate::geom::{netpbm, point_bounds, segment_distance};
+use crate::outline::{numeral_outline, Path};
+use crate::png::encode_png;
+use crate::style::GlyphStyle;
+use crate::typeset::digit_span;
+use crate::{Numeral, Set};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+// Signed distance fields measured in pixels, positive inside the glyph.
+// `spread` is the distance mapped onto the full 8-bit range, so the outline
+// itself sits at 128.
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SdfOptions {
+    // Pixels per glyph unit
+    pub scale: f32,
+    pub spread: f32,
+}
+
+impl Default for SdfOptions {
+    fn default() -> SdfOptions {
+        SdfOptions {
+            scale: 32.0,
+            spread: 4.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sdf {
+    pub width: usize,
+    pub height: usize,
+    pub spread: f32,
+    // Glyph-space point at the top left corner of the field
+    pub origin: [f32; 2],
+    pub values: Vec<f32>,
+}
+
+pub fn distance_field(
+    path: &Path,
+    width: usize,
+    height: usize,
+    origin: [f32; 2],
+    scale: f32,
+    spread: f32,
+) -> Sdf {
+    let mut segments: Vec<([f32; 2], [f32; 2])> = Vec::new();
+    for c in &path.contours {
+        let n = c.points.len();
+        for i in 0..n {
+            segments.push((c.points[i], c.points[(i + 1) % n]));
+        }
+    }
+    let mut values = Vec::with_capacity(width * height);
+    for y in 0..height {
+        for x in 0..width {
+            let p = [
+                origin[0] + (x as f32 + 0.5) / scale,
+                origin[1] - (y as f32 + 0.5) / scale,
+            ];
+            let d = segments
+                .iter()
+                .fold(f32::MAX, |d, (a, b)| d.min(segment_distance(p, *a, *b)))
+                * scale;
+            values.push(if path.contains(p) { d } else { -d });
+        }
+    }
+    Sdf {
+        width,
+        height,
+        spread,
+        origin,
+        values,
+    }
+}
+
+impl Sdf {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.values
+            .iter()
+            .map(|d| ((0.5 + d / (2.0 * self.spread)).clamp(0.0, 1.0) * 255.0).round() as u8)
+            .collect()
+    }
+    pub fn to_pgm(&self) -> Vec<u8> {
+        netpbm("P5", self.width, self.height, &self.to_bytes())
+    }
+    pub fn to_png(&self) -> Vec<u8> {
+        encode_png(self.width, self.height, 1, &self.to_bytes())
+    }
+}
+
+// The field is padded by the spread on every side so the falloff is complete.
+pub fn path_sdf(path: &Path, opts: &SdfOptions) -> Sdf {
+    let [x0, y0, x1, y1] =
+        point_bounds(path.contours.iter().flat_map(|c| c.points.iter().copied()))
+            .unwrap_or_default();
+    let pad = opts.spread.ceil();
+    let width = (((x1 - x0) * opts.scale + 2.0 * pad).ceil() as usize).max(1);
+    let height = (((y1 - y0) * opts.scale + 2.0 * pad).ceil() as usize).max(1);
+    let origin = [x0 - pad / opts.scale, y1 + pad / opts.scale];
+    distance_field(path, width, height, origin, opts.scale, opts.spread)
+}
+
+pub fn numeral_sdf(u: Numeral, style: &GlyphStyle, opts: &SdfOptions) -> Sdf {
+    path_sdf(&numeral_outline(u, style), opts)
+}
+
+// Serialized without the pixels, in the same form as `Atlas`, so `to_json`
+// works without the `serde` feature too. Each rect is a whole field, spread
+// included.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SdfAtlas {
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub pixels: Vec<u8>,
+    pub width: usize,
+    pub height: usize,
+    pub cell: [usize; 2],
+    // Pixels per glyph unit
+    pub scale: f32,
+    pub spread: f32,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "glyph_map"))]
+    pub glyphs: Vec<AtlasGlyph>,
+}
+
+impl SdfAtlas {
+    pub fn get(&self, encoding: &str) -> Option<&AtlasGlyph> {
+        self.glyphs
+            .iter()
+            .find(|g| g.numeral.encoding() == encoding)
+    }
+    pub fn to_png(&self) -> Vec<u8> {
+        encode_png(self.width, self.height, 1, &self.pixels)
+    }
+    pub fn to_pgm(&self) -> Vec<u8> {
+        netpbm("P5", self.width, self.height, &self.pixels)
+    }
+    pub fn to_json(&self) -> String {
+        format!(
+            concat!(
+                "{{\"width\":{},\"height\":{},\"cell\":[{},{}],",
+                "\"scale\":{:?},\"spread\":{:?},\"glyphs\":{}}}"
+            ),
+            self.width,
+            self.height,
+            self.cell[0],
+            self.cell[1],
+            self.scale,
+            self.spread,
+            glyphs_json(&self.glyphs)
+        )
+    }
+}
+
+// Packs all 60 numerals into equal cells, `columns` to a row, each field at
+// the top left of its cell.
+pub fn sdf_atlas(style: &GlyphStyle, opts: &SdfOptions, columns: usize) -> SdfAtlas {
+    let fields: Vec<(Numeral, Sdf)> = Set::new()
+        .values()
+        .into_iter()
+        .map(|u| (u, numeral_sdf(u, style, opts)))
+        .collect();
+    let cw = fields.iter().map(|(_, f)| f.width).max().unwrap_or(0);
+    let ch = fields.iter().map(|(_, f)| f.height).max().unwrap_or(0);
+    let packer = Packer::new(fields.len(), [cw, ch], columns);
+    // `layout` puts the baseline half the glyph height below its origin
+    let baseline = -0.5 * style.height();
+    // the pen sits at the left of the digit span, as in `layout`
+    let [lx0, lx1] = digit_span(style);
+    let mut pixels = vec![0u8; packer.width * packer.height];
+    let mut glyphs = Vec::new();
+    for (i, (u, f)) in fields.iter().enumerate() {
+        let [cx, cy] = packer.cell(i);
+        let bytes = f.to_bytes();
+        for y in 0..f.height {
+            let row = (cy + y) * packer.width + cx;
+            pixels[row..row + f.width].copy_from_slice(&bytes[y * f.width..(y + 1) * f.width]);
+        }
+        let pen = [
+            cx as f32 + (lx0 - f.origin[0]) * opts.scale,
+            cy as f32 + (f.origin[1] - baseline) * opts.scale,
+        ];
+        glyphs.push(packer.glyph(
+            *u,
+            [cx, cy, f.width, f.height],
+            pen,
+            (lx1 - lx0) * opts.scale,
+        ));
+    }
+    SdfAtlas {
+        pixels,
+        width: packer.width,
+        height: packer.height,
+        cell: [cw, ch],
+        scale: opts.scale,
+        spread: opts.spread,
+        glyphs,
+    }
+}
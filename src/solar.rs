@@ -0,0 +1,97 @@
+use crate::constants::constants::*;
+use crate::Numeral;
+
+// Low-precision solar coordinates after Meeus, "Astronomical Algorithms",
+// ch. 7 and 25. Good to about 0.01°, and the Sun takes about a quarter of an
+// hour to cover that, so a date within roughly ±15 minutes of a cusp can land
+// on either sign. Times are used as given, with no ΔT (TT − UT, about 69 s)
+// applied; that error is small next to the series itself.
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+pub fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        }
+        _ => panic!("Can only index months 1 - 12"),
+    }
+}
+
+// Julian day for a Gregorian calendar date, `hour` in decimal hours of UT.
+pub fn julian_day(year: i32, month: u8, day: u8, hour: f64) -> f64 {
+    if day < 1 || day > days_in_month(year, month) || !(0.0..24.0).contains(&hour) {
+        panic!("Invalid date!")
+    }
+    let (mut y, mut m) = (year as f64, month as f64);
+    if month <= 2 {
+        y -= 1.0;
+        m += 12.0;
+    }
+    let a = (y / 100.0).floor();
+    let b = 2.0 - a + (a / 4.0).floor();
+    (365.25 * (y + 4716.0)).floor() + (30.6001 * (m + 1.0)).floor() + day as f64 + hour / 24.0 + b
+        - 1524.5
+}
+
+// Apparent ecliptic longitude of the Sun in degrees, 0 ≤ λ < 360.
+pub fn solar_longitude(jd: f64) -> f64 {
+    let t = (jd - 2451545.0) / 36525.0;
+    let l0 = 280.46646 + 36000.76983 * t + 0.0003032 * t * t;
+    let m = (357.52911 + 35999.05029 * t - 0.0001537 * t * t).to_radians();
+    let c = (1.914602 - 0.004817 * t - 0.000014 * t * t) * m.sin()
+        + (0.019993 - 0.000101 * t) * (2.0 * m).sin()
+        + 0.000289 * (3.0 * m).sin();
+    let omega = (125.04 - 1934.136 * t).to_radians();
+    let lambda = l0 + c - 0.00569 - 0.00478 * omega.sin();
+    lambda.rem_euclid(360.0)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SunSign {
+    longitude: f64,
+}
+
+impl SunSign {
+    pub fn new(year: i32, month: u8, day: u8, hour: f64) -> SunSign {
+        SunSign::from_julian_day(julian_day(year, month, day, hour))
+    }
+    pub fn from_julian_day(jd: f64) -> SunSign {
+        SunSign::from_longitude(solar_longitude(jd))
+    }
+    pub fn from_longitude(longitude: f64) -> SunSign {
+        SunSign {
+            longitude: longitude.rem_euclid(360.0),
+        }
+    }
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+    // 0 is aries, counting through the zodiac to 11 for pisces
+    pub fn sign_index(&self) -> u8 {
+        ((self.longitude / 30.0) as u8).min(11)
+    }
+    // WESTERN_SIGNS starts at pisces, so aries sits at duodecimal 1
+    pub fn duodecimal_index(&self) -> u8 {
+        (self.sign_index() + 1) % 12
+    }
+    pub fn sign(&self) -> &str {
+        WESTERN_SIGNS[self.duodecimal_index() as usize]
+    }
+    pub fn degree(&self) -> f64 {
+        self.longitude - self.sign_index() as f64 * 30.0
+    }
+    pub fn numerals(&self) -> Vec<Numeral> {
+        (0..5)
+            .map(|d| Numeral::new(d * 12 + self.duodecimal_index()))
+            .collect()
+    }
+}
@@ -0,0 +1,172 @@
+use crate::curve::DiacriticCurve;
+use crate::mesh::Mesh;
+use crate::unit_diacritic::{Diacritic, DiacriticError, DIACRITIC_RADIUS};
+use crate::unit_glyph::{double_section_select, glyph_vertices_with_stem, y_center, STEM_OFFSET};
+use crate::unit_numeral::{DIACRITIC_GAP, DIACRITIC_SCALE};
+use crate::Numeral;
+use std::f32::consts::PI;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub const PRESETS: [&str; 4] = ["regular", "bold", "condensed", "italic"];
+
+// Everything that shapes a numeral before it reaches a renderer. The
+// defaults reproduce the loose-parameter functions in the unit modules.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct GlyphStyle {
+    // Stroke angle of the glyph legs
+    pub angle: f32,
+    pub weight: f32,
+    pub flip: bool,
+    // Samples along each diacritic curve
+    pub sample_rate: usize,
+    pub diacritic_scale: f32,
+    // Space between the glyph apex and the diacritic
+    pub diacritic_gap: f32,
+    pub diacritic_radius: f32,
+    // Weights between the base line and the top of the stem bars
+    pub stem_offset: f32,
+    // Horizontal scale of the whole numeral; below 1 condenses it
+    pub width: f32,
+    // Horizontal shear per unit of height; positive leans right
+    pub slant: f32,
+}
+
+impl Default for GlyphStyle {
+    fn default() -> GlyphStyle {
+        GlyphStyle::regular()
+    }
+}
+
+impl GlyphStyle {
+    pub fn regular() -> GlyphStyle {
+        GlyphStyle {
+            angle: PI / 3.0,
+            weight: 0.1,
+            flip: false,
+            sample_rate: 24,
+            diacritic_scale: DIACRITIC_SCALE,
+            diacritic_gap: DIACRITIC_GAP,
+            diacritic_radius: DIACRITIC_RADIUS,
+            stem_offset: STEM_OFFSET,
+            width: 1.0,
+            slant: 0.0,
+        }
+    }
+    pub fn bold() -> GlyphStyle {
+        GlyphStyle {
+            weight: 0.16,
+            diacritic_radius: 1.25 * DIACRITIC_RADIUS,
+            ..GlyphStyle::regular()
+        }
+    }
+    pub fn condensed() -> GlyphStyle {
+        GlyphStyle {
+            width: 0.7,
+            weight: 0.08,
+            ..GlyphStyle::regular()
+        }
+    }
+    pub fn italic() -> GlyphStyle {
+        GlyphStyle {
+            slant: 0.25,
+            ..GlyphStyle::regular()
+        }
+    }
+    pub fn preset(name: &str) -> Option<GlyphStyle> {
+        match name {
+            "regular" => Some(GlyphStyle::regular()),
+            "bold" => Some(GlyphStyle::bold()),
+            "condensed" => Some(GlyphStyle::condensed()),
+            "italic" => Some(GlyphStyle::italic()),
+            _ => None,
+        }
+    }
+    // The style with the loose parameters the unit modules take
+    pub fn with(radians: f32, weight: f32, flip: bool, sample_rate: usize) -> GlyphStyle {
+        GlyphStyle {
+            angle: radians,
+            weight,
+            flip,
+            sample_rate,
+            ..GlyphStyle::regular()
+        }
+    }
+
+    // Styles can come from user config, so renderers never trust the sample
+    // rate; this reports what they would otherwise quietly raise
+    pub fn validate(&self) -> Result<(), DiacriticError> {
+        for d in Diacritic::ALL {
+            d.vertices(self.sample_rate)?;
+        }
+        Ok(())
+    }
+    // The style's sample rate, raised to the least the diacritic can draw with
+    pub fn sample_rate(&self, diacritic: Diacritic) -> usize {
+        self.sample_rate.max(diacritic.min_sample_rate())
+    }
+
+    // Height of the glyph body, which the layout code lines up on
+    pub fn height(&self) -> f32 {
+        self.angle.sin().abs()
+    }
+    fn shape(&self, mesh: Mesh) -> Mesh {
+        if self.width == 1.0 && self.slant == 0.0 {
+            return mesh;
+        }
+        mesh.scale(self.width, 1.0).skew(self.slant, 0.0)
+    }
+    // Centered on the origin like the glyph inside `numeral_mesh`
+    pub fn glyph_mesh(&self, u: Numeral) -> Mesh {
+        let [_, dy, _] = y_center([0.0; 3], self.angle, self.flip);
+        let v = glyph_vertices_with_stem(self.angle, self.weight, self.flip, self.stem_offset);
+        self.shape(Mesh::new(v.to_vec(), double_section_select(u)).translate(0.0, dy))
+    }
+    // Unscaled, spanning x in [-1, 1]
+    pub fn diacritic_mesh(&self, diacritic: Diacritic) -> Mesh {
+        diacritic
+            .mesh_with_radius(self.sample_rate(diacritic), self.diacritic_radius)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+    // Where the unscaled diacritic's origin lands, following the anchoring
+    // rules in `unit_numeral`
+    pub fn diacritic_offset(&self, diacritic: Diacritic) -> [f32; 2] {
+        let apex = 0.5 * self.height();
+        let (lo, hi) = self
+            .diacritic_mesh(diacritic)
+            .vertices
+            .iter()
+            .fold((f32::MAX, f32::MIN), |(lo, hi), p| {
+                (lo.min(p[1]), hi.max(p[1]))
+            });
+        if self.flip {
+            [0.0, -apex - self.diacritic_gap - hi * self.diacritic_scale]
+        } else {
+            [0.0, apex + self.diacritic_gap - lo * self.diacritic_scale]
+        }
+    }
+    pub fn diacritic_curve(&self, diacritic: Diacritic) -> DiacriticCurve {
+        DiacriticCurve::new(diacritic, self.diacritic_radius)
+    }
+    // The affine map `place_diacritic` applies, for geometry that is not a
+    // mesh such as Bezier paths
+    pub fn diacritic_transform(&self, diacritic: Diacritic) -> [[f32; 3]; 2] {
+        let [ox, oy] = self.diacritic_offset(diacritic);
+        let (s, w, k) = (self.diacritic_scale, self.width, self.slant);
+        [[w * s, k * s, w * ox + k * oy], [0.0, s, oy]]
+    }
+    // Places an unscaled diacritic shaped mesh onto the numeral
+    pub fn place_diacritic(&self, diacritic: Diacritic, mesh: &Mesh) -> Mesh {
+        let [ox, oy] = self.diacritic_offset(diacritic);
+        let s = self.diacritic_scale;
+        self.shape(mesh.scale(s, s).translate(ox, oy))
+    }
+    pub fn numeral_mesh(&self, u: Numeral) -> Mesh {
+        let d = u.diacritic();
+        self.glyph_mesh(u)
+            .merge(&self.place_diacritic(d, &self.diacritic_mesh(d)))
+    }
+}
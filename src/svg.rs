@@ -0,0 +1,139 @@
+use crate::constants::constants::*;
+use crate::geom::{cross2d, xy};
+use crate::mesh::Mesh;
+use crate::style::GlyphStyle;
+use crate::Numeral;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgStyle {
+    pub glyph: GlyphStyle,
+    pub fill: String,
+    pub stroke: Option<String>,
+    pub stroke_width: f32,
+    // Fills each numeral by its diacritic tier instead of `fill` when set
+    pub tier_colors: Option<[String; 5]>,
+    // Pixels per glyph unit for standalone documents
+    pub scale: f32,
+    // Draws diacritics as cubic Beziers within this distance of the exact
+    // curve instead of as sampled triangles
+    pub curve_tolerance: Option<f32>,
+}
+
+impl Default for SvgStyle {
+    fn default() -> SvgStyle {
+        SvgStyle {
+            glyph: GlyphStyle::default(),
+            fill: "black".to_string(),
+            stroke: None,
+            stroke_width: 0.02,
+            tier_colors: None,
+            scale: 100.0,
+            curve_tolerance: None,
+        }
+    }
+}
+
+pub fn default_tier_colors() -> [String; 5] {
+    COLORS.map(|c| c.to_string())
+}
+
+fn compose(u: Numeral, style: &SvgStyle) -> Mesh {
+    style.glyph.numeral_mesh(u)
+}
+
+// Every triangle is written counter-clockwise so overlapping sections never
+// cancel each other out under the nonzero fill rule.
+pub fn triangles_path(mesh: &Mesh) -> String {
+    let mut d = String::new();
+    for [a, mut b, mut c] in mesh.triangles() {
+        let cross = cross2d(xy(a), xy(b), xy(c));
+        if cross.abs() < f32::EPSILON {
+            continue;
+        }
+        if cross < 0.0 {
+            std::mem::swap(&mut b, &mut c);
+        }
+        // SVG's y axis points down
+        d += &format!(
+            "M{:.4} {:.4}L{:.4} {:.4}L{:.4} {:.4}Z",
+            a[0], -a[1], b[0], -b[1], c[0], -c[1]
+        );
+    }
+    d
+}
+
+pub(crate) fn fill_color(u: Numeral, style: &SvgStyle) -> &str {
+    match &style.tier_colors {
+        Some(colors) => &colors[u.diacritic_index() as usize],
+        None => &style.fill,
+    }
+}
+
+// Colors are free-form strings, so they are escaped before going into an
+// attribute value.
+fn escape_attr(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('"', "&quot;")
+}
+
+// The stroke is laid down first and the fill painted over it, which hides
+// the seams between triangles and leaves only the outer edge visible.
+pub fn paint(d: &str, fill: &str, style: &SvgStyle) -> String {
+    let mut p = String::new();
+    if let Some(stroke) = &style.stroke {
+        p += &format!(
+            "<path d=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\" stroke-linejoin=\"round\"/>",
+            d,
+            escape_attr(stroke),
+            style.stroke_width * 2.0
+        );
+    }
+    p + &format!("<path d=\"{}\" fill=\"{}\"/>", d, escape_attr(fill))
+}
+
+pub fn numeral_group(u: Numeral, style: &SvgStyle) -> String {
+    let d = match style.curve_tolerance {
+        Some(tolerance) => {
+            let g = &style.glyph;
+            let di = u.diacritic();
+            let curve = g
+                .diacritic_curve(di)
+                .beziers(tolerance)
+                .transform(g.diacritic_transform(di));
+            triangles_path(&g.glyph_mesh(u)) + &curve.to_svg_d()
+        }
+        None => triangles_path(&compose(u, style)),
+    };
+    format!(
+        "<g data-numeral=\"{}\">{}</g>",
+        u.encoding(),
+        paint(&d, fill_color(u, style), style)
+    )
+}
+
+// Wraps `body` in a standalone document whose view box covers `bounds`
+// (x0, y0, x1, y1 in glyph units, y up).
+pub fn svg_document(bounds: [f32; 4], body: &str, style: &SvgStyle) -> String {
+    let [x0, y0, x1, y1] = bounds;
+    let pad = style.stroke.as_ref().map_or(0.0, |_| style.stroke_width) + 0.05;
+    let (w, h) = (x1 - x0 + 2.0 * pad, y1 - y0 + 2.0 * pad);
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{:.2}\" height=\"{:.2}\" viewBox=\"{:.4} {:.4} {:.4} {:.4}\">{}</svg>\n",
+        w * style.scale,
+        h * style.scale,
+        x0 - pad,
+        -y1 - pad,
+        w,
+        h,
+        body
+    )
+}
+
+pub fn numeral_svg(u: Numeral, style: &SvgStyle) -> String {
+    svg_document(
+        compose(u, style).bounds2d(),
+        &numeral_group(u, style),
+        style,
+    )
+}
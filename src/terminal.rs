@@ -0,0 +1,240 @@
+// Numerals drawn with Unicode characters for terminals. The composed meshes
+// are rasterized onto a canvas of dots, which are packed 2×4 into braille
+// patterns or 1×2 into half blocks; both keep the dots square on a typical
+// terminal font.
+use crate::geom::union;
+use crate::mesh::Mesh;
+use crate::pick::tight_bounds;
+use crate::raster::coverage;
+use crate::style::GlyphStyle;
+use crate::typeset::{layout, separator_mesh, LayoutOptions};
+use crate::{Diacritic, Glyph, Numeral, Uint};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Cells {
+    #[default]
+    Braille,
+    HalfBlock,
+}
+
+impl Cells {
+    // Dots across and down each character
+    pub fn size(&self) -> (usize, usize) {
+        match self {
+            Cells::Braille => (2, 4),
+            Cells::HalfBlock => (1, 2),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TerminalOptions {
+    pub cells: Cells,
+    // Dots per glyph unit
+    pub scale: f32,
+    // Share of a dot the geometry must cover to light it
+    pub threshold: f32,
+    // Paints each numeral in its tier's `Numeral::color` with ANSI escapes
+    pub colors: bool,
+    // Glyph units between the columns and rows of the `Set` grid
+    pub spacing: f32,
+}
+
+impl Default for TerminalOptions {
+    fn default() -> TerminalOptions {
+        TerminalOptions {
+            cells: Cells::Braille,
+            scale: 16.0,
+            threshold: 0.5,
+            colors: false,
+            spacing: 0.3,
+        }
+    }
+}
+
+// Foreground code for the color names `Numeral::color` returns
+pub fn ansi_color(name: &str) -> Option<u8> {
+    match name {
+        "red" => Some(31),
+        "green" => Some(32),
+        "yellow" => Some(33),
+        "blue" => Some(34),
+        "purple" => Some(35),
+        _ => None,
+    }
+}
+
+// Lit dots with the ANSI color each was painted in, 0 for none
+struct Canvas {
+    width: usize,
+    height: usize,
+    dots: Vec<Option<u8>>,
+    origin: [f32; 2],
+    scale: f32,
+}
+
+impl Canvas {
+    fn new(bounds: [f32; 4], opts: &TerminalOptions) -> Canvas {
+        let [x0, y0, x1, y1] = bounds;
+        let (cw, ch) = opts.cells.size();
+        let round_up = |n: usize, k: usize| n.div_ceil(k).max(1) * k;
+        let width = round_up(((x1 - x0) * opts.scale).ceil() as usize, cw);
+        let height = round_up(((y1 - y0) * opts.scale).ceil() as usize, ch);
+        Canvas {
+            width,
+            height,
+            dots: vec![None; width * height],
+            origin: [x0, y1],
+            scale: opts.scale,
+        }
+    }
+    fn paint(&mut self, mesh: &Mesh, color: u8, threshold: f32) {
+        let Some([x0, y0, x1, y1]) = tight_bounds(mesh) else {
+            return;
+        };
+        // only the dots under the mesh need sampling
+        let left = ((x0 - self.origin[0]) * self.scale).floor().max(0.0) as usize;
+        let top = ((self.origin[1] - y1) * self.scale).floor().max(0.0) as usize;
+        let right = (((x1 - self.origin[0]) * self.scale).ceil() as usize).min(self.width);
+        let bottom = (((self.origin[1] - y0) * self.scale).ceil() as usize).min(self.height);
+        if left >= right || top >= bottom {
+            return;
+        }
+        let (w, h) = (right - left, bottom - top);
+        let origin = [
+            self.origin[0] + left as f32 / self.scale,
+            self.origin[1] - top as f32 / self.scale,
+        ];
+        let cov = coverage(mesh, w, h, origin, self.scale, 4);
+        for (i, c) in cov.iter().enumerate() {
+            if *c >= threshold {
+                self.dots[(top + i / w) * self.width + left + i % w] = Some(color);
+            }
+        }
+    }
+    fn render(&self, cells: Cells) -> String {
+        let (cw, ch) = cells.size();
+        let mut out = String::new();
+        for row in 0..self.height / ch {
+            let mut current: u8 = 0;
+            for col in 0..self.width / cw {
+                let dot =
+                    |dx: usize, dy: usize| self.dots[(row * ch + dy) * self.width + col * cw + dx];
+                let c = match cells {
+                    Cells::Braille => {
+                        // dot numbering runs down the left column, then the
+                        // right, with the bottom row added last
+                        const BITS: [[u32; 4]; 2] =
+                            [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+                        let mut bits = 0;
+                        for (dx, column) in BITS.iter().enumerate() {
+                            for (dy, b) in column.iter().enumerate() {
+                                if dot(dx, dy).is_some() {
+                                    bits |= b;
+                                }
+                            }
+                        }
+                        if bits == 0 {
+                            ' '
+                        } else {
+                            char::from_u32(0x2800 + bits).unwrap()
+                        }
+                    }
+                    Cells::HalfBlock => match (dot(0, 0).is_some(), dot(0, 1).is_some()) {
+                        (true, true) => '█',
+                        (true, false) => '▀',
+                        (false, true) => '▄',
+                        (false, false) => ' ',
+                    },
+                };
+                let color = (0..cw)
+                    .flat_map(|dx| (0..ch).map(move |dy| (dx, dy)))
+                    .find_map(|(dx, dy)| dot(dx, dy))
+                    .unwrap_or(current);
+                if color != current {
+                    out += &format!("\x1b[{}m", if color == 0 { 39 } else { color });
+                    current = color;
+                }
+                out.push(c);
+            }
+            if current != 0 {
+                out += "\x1b[0m";
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+fn tier_color(u: Numeral, opts: &TerminalOptions) -> u8 {
+    if opts.colors {
+        ansi_color(u.color()).unwrap_or(0)
+    } else {
+        0
+    }
+}
+
+pub fn numeral_terminal(u: Numeral, style: &GlyphStyle, opts: &TerminalOptions) -> String {
+    let mesh = style.numeral_mesh(u);
+    let mut canvas = Canvas::new(tight_bounds(&mesh).unwrap_or_default(), opts);
+    canvas.paint(&mesh, tier_color(u, opts), opts.threshold);
+    canvas.render(opts.cells)
+}
+
+pub fn uint_terminal(
+    u: &Uint,
+    layout_opts: &LayoutOptions,
+    style: &GlyphStyle,
+    opts: &TerminalOptions,
+) -> String {
+    let layout = layout(u, layout_opts, style);
+    let glyphs: Vec<(Numeral, Mesh)> = layout
+        .glyphs
+        .iter()
+        .map(|p| {
+            let mesh = style.numeral_mesh(p.numeral);
+            (p.numeral, mesh.translate(p.origin[0], p.origin[1]))
+        })
+        .collect();
+    let separators = separator_mesh(&layout, layout_opts, style);
+    let bounds = union(
+        glyphs
+            .iter()
+            .map(|(_, m)| m)
+            .chain([&separators])
+            .filter_map(tight_bounds),
+    )
+    .unwrap_or_default();
+    let mut canvas = Canvas::new(bounds, opts);
+    for (n, m) in &glyphs {
+        canvas.paint(m, tier_color(*n, opts), opts.threshold);
+    }
+    canvas.paint(&separators, 0, opts.threshold);
+    canvas.render(opts.cells)
+}
+
+// The whole set with one row per diacritic and one column per glyph
+pub fn set_terminal(style: &GlyphStyle, opts: &TerminalOptions) -> String {
+    let meshes: Vec<(Numeral, Mesh)> = Diacritic::ALL
+        .iter()
+        .flat_map(|d| d.numerals())
+        .map(|u| (u, style.numeral_mesh(u)))
+        .collect();
+    let [x0, y0, x1, y1] =
+        union(meshes.iter().filter_map(|(_, m)| tight_bounds(m))).unwrap_or_default();
+    let pitch = [x1 - x0 + opts.spacing, y1 - y0 + opts.spacing];
+    let (columns, rows) = (Glyph::ALL.len(), Diacritic::ALL.len());
+    let bounds = [
+        x0,
+        y1 - rows as f32 * pitch[1] + opts.spacing,
+        x0 + columns as f32 * pitch[0] - opts.spacing,
+        y1,
+    ];
+    let mut canvas = Canvas::new(bounds, opts);
+    for (u, m) in &meshes {
+        let (row, col) = (u.diacritic_index() as f32, u.duodecimal_index() as f32);
+        let placed = m.translate(col * pitch[0], -row * pitch[1]);
+        canvas.paint(&placed, tier_color(*u, opts), opts.threshold);
+    }
+    canvas.render(opts.cells)
+}
@@ -0,0 +1,193 @@
+use crate::mesh::Mesh;
+use crate::style::GlyphStyle;
+use crate::svg::{numeral_group, paint, svg_document, triangles_path, SvgStyle};
+use crate::{Diacritic, Numeral, Uint};
+use std::collections::HashMap;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Separator {
+    Dot,
+    Colon,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutOptions {
+    // Space after every digit and separator
+    pub spacing: f32,
+    // Extra advance between specific pairs of neighbouring digits
+    pub kerning: HashMap<(Numeral, Numeral), f32>,
+    pub separator: Option<Separator>,
+    pub separator_size: f32,
+    // Lines are wrapped before a digit that would cross this width
+    pub max_width: Option<f32>,
+    pub line_height: f32,
+}
+
+impl Default for LayoutOptions {
+    fn default() -> LayoutOptions {
+        LayoutOptions {
+            spacing: 0.15,
+            kerning: HashMap::new(),
+            separator: None,
+            separator_size: 0.1,
+            max_width: None,
+            line_height: 2.0,
+        }
+    }
+}
+
+// `origin` is where the composed numeral's own origin lands; the glyph body
+// sits on its line's baseline, with the first baseline at y = 0.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Placement {
+    pub numeral: Numeral,
+    pub index: usize,
+    pub line: usize,
+    pub origin: [f32; 2],
+    pub advance: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layout {
+    pub glyphs: Vec<Placement>,
+    // Center of each separator, on the baseline of its line
+    pub separators: Vec<[f32; 2]>,
+    pub lines: usize,
+    pub width: f32,
+}
+
+pub fn baseline(line: usize, opts: &LayoutOptions) -> f32 {
+    -(line as f32) * opts.line_height
+}
+
+// The x span every digit takes up in a line: the glyph cell with all its
+// section vertices, widened by any diacritic reaching past it. Digits are
+// set monospace, so the lone stem of 1 is inked over about a quarter of the
+// span 0 fills. `pick::numeral_bounds` gives the inked extent instead.
+pub fn digit_span(style: &GlyphStyle) -> [f32; 2] {
+    let body = style.glyph_mesh(Numeral::new(0)).vertices;
+    let diacritics = Diacritic::ALL.iter().flat_map(|d| {
+        style
+            .place_diacritic(*d, &style.diacritic_mesh(*d))
+            .vertices
+    });
+    body.into_iter()
+        .chain(diacritics)
+        .fold([f32::MAX, f32::MIN], |[x0, x1], p| {
+            [x0.min(p[0]), x1.max(p[0])]
+        })
+}
+
+pub fn layout(u: &Uint, opts: &LayoutOptions, style: &GlyphStyle) -> Layout {
+    let apex = 0.5 * style.height();
+    let sep_advance = opts
+        .separator
+        .map_or(0.0, |_| opts.separator_size + opts.spacing);
+    let mut glyphs: Vec<Placement> = Vec::new();
+    let mut separators: Vec<[f32; 2]> = Vec::new();
+    let mut pen = 0.0;
+    let mut line = 0;
+    let mut width: f32 = 0.0;
+    let [x0, x1] = digit_span(style);
+    let w = x1 - x0;
+    for (i, n) in u.values().into_iter().enumerate() {
+        let prev = glyphs.last().filter(|p| p.line == line).map(|p| p.numeral);
+        if let Some(p) = prev {
+            let kern = opts.kerning.get(&(p, n)).copied().unwrap_or(0.0);
+            let wraps = opts
+                .max_width
+                .is_some_and(|max| pen + kern + sep_advance + w > max);
+            if wraps {
+                line += 1;
+                pen = 0.0;
+            } else {
+                pen += kern;
+                if opts.separator.is_some() {
+                    separators.push([pen + opts.separator_size / 2.0, baseline(line, opts)]);
+                    pen += sep_advance;
+                }
+            }
+        }
+        glyphs.push(Placement {
+            numeral: n,
+            index: i,
+            line,
+            origin: [pen - x0, baseline(line, opts) + apex],
+            advance: w + opts.spacing,
+        });
+        width = width.max(pen + w);
+        pen += w + opts.spacing;
+    }
+    Layout {
+        glyphs,
+        separators,
+        lines: line + 1,
+        width,
+    }
+}
+
+fn square(c: [f32; 2], size: f32) -> Mesh {
+    let h = size / 2.0;
+    Mesh::new(
+        vec![
+            [c[0] - h, c[1] - h, 0.0],
+            [c[0] + h, c[1] - h, 0.0],
+            [c[0] + h, c[1] + h, 0.0],
+            [c[0] - h, c[1] + h, 0.0],
+        ],
+        vec![0, 1, 2, 0, 2, 3],
+    )
+}
+
+pub(crate) fn separator_mesh(layout: &Layout, opts: &LayoutOptions, style: &GlyphStyle) -> Mesh {
+    let height = style.height();
+    let size = opts.separator_size;
+    let rows: Vec<f32> = match opts.separator {
+        Some(Separator::Dot) => vec![size / 2.0],
+        Some(Separator::Colon) => vec![0.25 * height, 0.75 * height],
+        None => vec![],
+    };
+    let mut mesh = Mesh::default();
+    for s in &layout.separators {
+        for r in &rows {
+            mesh.append(&square([s[0], s[1] + r], size));
+        }
+    }
+    mesh
+}
+
+fn glyphs_mesh(layout: &Layout, style: &GlyphStyle) -> Mesh {
+    let mut mesh = Mesh::default();
+    for p in &layout.glyphs {
+        let glyph = style.numeral_mesh(p.numeral);
+        mesh.append(&glyph.translate(p.origin[0], p.origin[1]));
+    }
+    mesh
+}
+
+pub fn typeset_mesh(u: &Uint, opts: &LayoutOptions, style: &GlyphStyle) -> Mesh {
+    let layout = layout(u, opts, style);
+    let mut mesh = glyphs_mesh(&layout, style);
+    mesh.append(&separator_mesh(&layout, opts, style));
+    mesh
+}
+
+pub fn typeset_svg(u: &Uint, opts: &LayoutOptions, style: &SvgStyle) -> String {
+    let layout = layout(u, opts, &style.glyph);
+    let mut body = String::new();
+    for p in &layout.glyphs {
+        body += &format!(
+            "<g transform=\"translate({:.4} {:.4})\">{}</g>",
+            p.origin[0],
+            -p.origin[1],
+            numeral_group(p.numeral, style)
+        );
+    }
+    let separators = separator_mesh(&layout, opts, &style.glyph);
+    if !separators.indices.is_empty() {
+        body += &paint(&triangles_path(&separators), &style.fill, style);
+    }
+    let mut mesh = glyphs_mesh(&layout, &style.glyph);
+    mesh.append(&separators);
+    svg_document(mesh.bounds2d(), &body, style)
+}
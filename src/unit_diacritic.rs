@@ -1,119 +1,273 @@
+use crate::constants::constants::{DIACRITIC_CHARS, DIACRITIC_NAME};
+use crate::mesh::Mesh;
+use crate::unit_glyph::Glyph;
+use crate::Numeral;
 use std::f32::consts::{PI, TAU};
+use std::fmt;
 
-pub fn diacritic_vertices(diacritic: u8, sample_rate: usize) -> Vec<[f32; 3]> {
-    let mut v: Vec<[f32; 3]> = Vec::new();
-    let step = 2.0 / sample_rate as f32;
-    let mut x = -1.0;
-    let tau_step = TAU / sample_rate as f32;
-    let mut theta: f32 = 0.0;
-    let radius = 1.0 / PI;
+pub const DIACRITIC_RADIUS: f32 = 1.0 / PI;
 
-    fn ta(r: f32, theta: f32) -> [f32; 2] {
-        [
-            (r / 2.0).sqrt() * theta.sin(),
-            (r / 2.0).sqrt() * theta.cos(),
-        ]
-    }
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Diacritic {
+    Ta,
+    Shey,
+    Ree,
+    Jo,
+    Wu,
+}
 
-    fn shay(r: f32, x: f32) -> [f32; 2] {
-        [
-            -((x * PI).sin() / (2.0 * PI)) + r,
-            -((x * PI).sin() / (2.0 * PI)) - r,
-        ]
-    }
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DiacriticError {
+    InvalidIndex(u8),
+    SampleRateTooLow {
+        diacritic: Diacritic,
+        sample_rate: usize,
+        min: usize,
+    },
+}
 
-    fn ree(r: f32, x: f32) -> [f32; 2] {
-        [
-            (-(x * PI).cos().abs() / PI) + 2.0 * r,
-            -(x * PI).cos().abs() / PI,
-        ]
+impl fmt::Display for DiacriticError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DiacriticError::InvalidIndex(i) => {
+                write!(f, "Can only index diacritics 0 - 4, got {}", i)
+            }
+            DiacriticError::SampleRateTooLow {
+                diacritic,
+                sample_rate,
+                min,
+            } => write!(
+                f,
+                "{:?} needs a sample rate of at least {}, got {}",
+                diacritic, min, sample_rate
+            ),
+        }
     }
+}
 
-    fn jo(r: f32, x: f32) -> [f32; 2] {
-        [(x / PI).abs(), (x / PI).abs() - 2.0 * r]
-    }
+impl std::error::Error for DiacriticError {}
 
-    fn wue(r: f32, x: f32) -> f32 {
-        -3.0 / PI * x * x + r
+impl TryFrom<u8> for Diacritic {
+    type Error = DiacriticError;
+    fn try_from(i: u8) -> Result<Diacritic, DiacriticError> {
+        Diacritic::ALL
+            .get(i as usize)
+            .copied()
+            .ok_or(DiacriticError::InvalidIndex(i))
     }
+}
+
+impl Diacritic {
+    pub const ALL: [Diacritic; 5] = [
+        Diacritic::Ta,
+        Diacritic::Shey,
+        Diacritic::Ree,
+        Diacritic::Jo,
+        Diacritic::Wu,
+    ];
 
-    fn wuxi(r: f32, index: usize, sample_rate: usize) -> f32 {
-        let wuxi0 = -(r * PI / 4.0).sqrt();
-        let step = (-wuxi0 * 2.0 * index as f32) / sample_rate as f32;
-        wuxi0 + step
+    // Same numbering as `Numeral::diacritic_index`
+    pub fn index(&self) -> u8 {
+        *self as u8
+    }
+    pub fn name(&self) -> &'static str {
+        DIACRITIC_NAME[self.index() as usize]
+    }
+    // First character of a numeral's encoding
+    pub fn char(&self) -> char {
+        DIACRITIC_CHARS[self.index() as usize]
+    }
+    pub fn from_char(c: char) -> Option<Diacritic> {
+        Diacritic::ALL.into_iter().find(|d| d.char() == c)
+    }
+    // The twelve numerals carrying this diacritic, in order
+    pub fn numerals(&self) -> impl Iterator<Item = Numeral> {
+        let d = *self;
+        Glyph::ALL
+            .into_iter()
+            .map(move |g| Numeral::from_parts(d, g))
+    }
+    // Ta needs three points on its rim to cover any area; the strips only
+    // need both ends.
+    pub fn min_sample_rate(&self) -> usize {
+        match self {
+            Diacritic::Ta => 3,
+            _ => 1,
+        }
+    }
+    fn check(&self, sample_rate: usize) -> Result<(), DiacriticError> {
+        if sample_rate < self.min_sample_rate() {
+            return Err(DiacriticError::SampleRateTooLow {
+                diacritic: *self,
+                sample_rate,
+                min: self.min_sample_rate(),
+            });
+        }
+        Ok(())
+    }
+    // Ta is a fan of every rim sample plus a copy of the first one past the
+    // start, stitching the disc shut; the others are (upper, lower) pairs.
+    pub fn vertex_count(&self, sample_rate: usize) -> usize {
+        match self {
+            Diacritic::Ta => sample_rate + 2,
+            _ => 2 * (sample_rate + 1),
+        }
+    }
+    pub fn index_count(&self, sample_rate: usize) -> usize {
+        match self {
+            Diacritic::Ta => 3 * sample_rate,
+            _ => 6 * sample_rate,
+        }
     }
 
-    fn wuyi(r: f32, x: f32) -> f32 {
-        -6.0 / PI * x * x - r / 2.0
+    pub fn vertices(&self, sample_rate: usize) -> Result<Vec<[f32; 3]>, DiacriticError> {
+        self.vertices_with_radius(sample_rate, DIACRITIC_RADIUS)
     }
 
-    for i in 0..=sample_rate {
-        match diacritic {
-            0 => {
-                let [x, y] = ta(radius, theta);
-                if i == sample_rate {
+    pub fn vertices_with_radius(
+        &self,
+        sample_rate: usize,
+        radius: f32,
+    ) -> Result<Vec<[f32; 3]>, DiacriticError> {
+        self.check(sample_rate)?;
+        let mut v: Vec<[f32; 3]> = Vec::new();
+        let step = 2.0 / sample_rate as f32;
+        let mut x = -1.0;
+        let tau_step = TAU / sample_rate as f32;
+        let mut theta: f32 = 0.0;
+
+        fn ta(r: f32, theta: f32) -> [f32; 2] {
+            [
+                (r / 2.0).sqrt() * theta.sin(),
+                (r / 2.0).sqrt() * theta.cos(),
+            ]
+        }
+
+        fn shay(r: f32, x: f32) -> [f32; 2] {
+            [
+                -((x * PI).sin() / (2.0 * PI)) + r,
+                -((x * PI).sin() / (2.0 * PI)) - r,
+            ]
+        }
+
+        fn ree(r: f32, x: f32) -> [f32; 2] {
+            [
+                (-(x * PI).cos().abs() / PI) + 2.0 * r,
+                -(x * PI).cos().abs() / PI,
+            ]
+        }
+
+        fn jo(r: f32, x: f32) -> [f32; 2] {
+            [(x / PI).abs(), (x / PI).abs() - 2.0 * r]
+        }
+
+        fn wue(r: f32, x: f32) -> f32 {
+            -3.0 / PI * x * x + r
+        }
+
+        fn wuxi(r: f32, index: usize, sample_rate: usize) -> f32 {
+            let wuxi0 = -(r * PI / 4.0).sqrt();
+            let step = (-wuxi0 * 2.0 * index as f32) / sample_rate as f32;
+            wuxi0 + step
+        }
+
+        fn wuyi(r: f32, x: f32) -> f32 {
+            -6.0 / PI * x * x - r / 2.0
+        }
+
+        for i in 0..=sample_rate {
+            match self {
+                Diacritic::Ta => {
+                    let [x, y] = ta(radius, theta);
                     v.push([x, y, 0.0]);
-                    let stitch = v[1].clone();
-                    v.push(stitch);
-                } else {
-                    v.push([x, y, 0.0])
+                }
+                Diacritic::Shey => {
+                    let [u, l] = shay(radius, x);
+                    v.push([x, u, 0.0]);
+                    v.push([x, l, 0.0]);
+                }
+                Diacritic::Ree => {
+                    let [u, l] = ree(radius, x);
+                    v.push([x, u, 0.0]);
+                    v.push([x, l, 0.0]);
+                }
+                Diacritic::Jo => {
+                    let [u, l] = jo(radius, x);
+                    v.push([x, u, 0.0]);
+                    v.push([x, l, 0.0]);
+                }
+                Diacritic::Wu => {
+                    let ye = wue(radius, x);
+                    let xi = wuxi(radius, i, sample_rate);
+                    let yi = wuyi(radius, xi);
+                    v.push([x, ye, 0.0]);
+                    v.push([xi, yi, 0.0]);
                 }
             }
-            1 => {
-                let [u, l] = shay(radius, x);
-                v.push([x, u, 0.0]);
-                v.push([x, l, 0.0]);
-            }
-            2 => {
-                let [u, l] = ree(radius, x);
-                v.push([x, u, 0.0]);
-                v.push([x, l, 0.0]);
-            }
-            3 => {
-                let [u, l] = jo(radius, x);
-                v.push([x, u, 0.0]);
-                v.push([x, l, 0.0]);
-            }
-            4 => {
-                let ye = wue(radius, x);
-                let xi = wuxi(radius, i, sample_rate);
-                let yi = wuyi(radius, xi);
-                v.push([x, ye, 0.0]);
-                v.push([xi, yi, 0.0]);
-            }
-            _ => println!("Invalid input!"),
+            x += step;
+            theta += tau_step;
         }
-        x += step;
-        theta += tau_step;
+        if *self == Diacritic::Ta {
+            v.push(v[1]);
+        }
+        Ok(v)
     }
-    v
-}
 
-pub fn gen_diacritic_indices(diacritic: u8, sample_rate: usize) -> Vec<u32> {
-    let mut v: Vec<u32> = Vec::new();
-
-    match diacritic {
-        0 => {
-            for j in 0..sample_rate {
-                let i = j as u32;
-                v.push(0);
-                v.push(i + 1);
-                v.push(i + 2);
-            }
-        }
-        _ => {
-            for j in 0..=((sample_rate - 1) * 2) {
-                let i = j as u32;
-                if i % 2 == 0 {
-                    v.push(i);
-                    v.push(i + 1);
-                    v.push(i + 3);
-                    v.push(i);
-                    v.push(i + 2);
-                    v.push(i + 3);
+    pub fn indices(&self, sample_rate: usize) -> Result<Vec<u32>, DiacriticError> {
+        self.check(sample_rate)?;
+        let mut v: Vec<u32> = Vec::new();
+        for j in 0..sample_rate as u32 {
+            match self {
+                Diacritic::Ta => v.extend([0, j + 1, j + 2]),
+                _ => {
+                    let i = 2 * j;
+                    v.extend([i, i + 1, i + 3, i, i + 2, i + 3]);
                 }
             }
         }
+        Ok(v)
+    }
+
+    pub fn mesh(&self, sample_rate: usize) -> Result<Mesh, DiacriticError> {
+        self.mesh_with_radius(sample_rate, DIACRITIC_RADIUS)
+    }
+
+    pub fn mesh_with_radius(
+        &self,
+        sample_rate: usize,
+        radius: f32,
+    ) -> Result<Mesh, DiacriticError> {
+        Ok(Mesh::new(
+            self.vertices_with_radius(sample_rate, radius)?,
+            self.indices(sample_rate)?,
+        ))
     }
-    v
+}
+
+// The loose functions below take the raw index, as `Numeral::diacritic_index`
+// gives it, and report a bad index or sample rate like the methods do.
+pub fn diacritic_vertices(
+    diacritic_index: u8,
+    sample_rate: usize,
+) -> Result<Vec<[f32; 3]>, DiacriticError> {
+    Diacritic::try_from(diacritic_index)?.vertices(sample_rate)
+}
+
+pub fn diacritic_vertices_with_radius(
+    diacritic_index: u8,
+    sample_rate: usize,
+    radius: f32,
+) -> Result<Vec<[f32; 3]>, DiacriticError> {
+    Diacritic::try_from(diacritic_index)?.vertices_with_radius(sample_rate, radius)
+}
+
+pub fn gen_diacritic_indices(
+    diacritic_index: u8,
+    sample_rate: usize,
+) -> Result<Vec<u32>, DiacriticError> {
+    Diacritic::try_from(diacritic_index)?.indices(sample_rate)
+}
+
+pub fn diacritic_mesh(diacritic_index: u8, sample_rate: usize) -> Result<Mesh, DiacriticError> {
+    Diacritic::try_from(diacritic_index)?.mesh(sample_rate)
 }
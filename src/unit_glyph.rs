@@ -1,5 +1,82 @@
+use crate::constants::constants::{DUODECIMALS, DUODECIMAL_NAME};
+use crate::mesh::Mesh;
+use crate::unit_diacritic::Diacritic;
 use crate::Numeral;
 use std::f32::consts::TAU;
+use std::fmt;
+
+// The twelve glyph bodies, numbered like `Numeral::duodecimal_index`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Glyph {
+    Zo,
+    Zee,
+    Bey,
+    Tree,
+    Kat,
+    Pen,
+    Hyx,
+    Sep,
+    Awk,
+    Neyn,
+    Dek,
+    Lev,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct InvalidGlyph(pub u8);
+
+impl fmt::Display for InvalidGlyph {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Can only index glyphs 0 - 11, got {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidGlyph {}
+
+impl TryFrom<u8> for Glyph {
+    type Error = InvalidGlyph;
+    fn try_from(i: u8) -> Result<Glyph, InvalidGlyph> {
+        Glyph::ALL.get(i as usize).copied().ok_or(InvalidGlyph(i))
+    }
+}
+
+impl Glyph {
+    pub const ALL: [Glyph; 12] = [
+        Glyph::Zo,
+        Glyph::Zee,
+        Glyph::Bey,
+        Glyph::Tree,
+        Glyph::Kat,
+        Glyph::Pen,
+        Glyph::Hyx,
+        Glyph::Sep,
+        Glyph::Awk,
+        Glyph::Neyn,
+        Glyph::Dek,
+        Glyph::Lev,
+    ];
+
+    pub fn index(&self) -> u8 {
+        *self as u8
+    }
+    pub fn name(&self) -> &'static str {
+        DUODECIMAL_NAME[self.index() as usize]
+    }
+    // Second character of a numeral's encoding
+    pub fn char(&self) -> char {
+        DUODECIMALS[self.index() as usize]
+    }
+    pub fn from_char(c: char) -> Option<Glyph> {
+        Glyph::ALL.into_iter().find(|g| g.char() == c)
+    }
+    // The five numerals sharing this body, one per diacritic
+    pub fn numerals(&self) -> impl Iterator<Item = Numeral> {
+        let g = *self;
+        Diacritic::ALL
+            .into_iter()
+            .map(move |d| Numeral::from_parts(d, g))
+    }
+}
 
 pub const INDICES: [u32; 66] = [
     0, 1, 4, 1, 4, 5, 1, 2, 5, 2, 5, 6, 2, 3, 6, 3, 6, 7, 4, 5, 8, 5, 8, 9, 6, 7, 10, 7, 10, 11, 8,
@@ -25,7 +102,14 @@ pub fn double_indices() -> [u32; 132] {
     di
 }
 
+// Weights between the base line and the top of the stem bars
+pub const STEM_OFFSET: f32 = 3.0;
+
 pub fn vertices(radians: f32, weight: f32) -> [[f32; 3]; 20] {
+    vertices_with_stem(radians, weight, STEM_OFFSET)
+}
+
+pub fn vertices_with_stem(radians: f32, weight: f32, stem_offset: f32) -> [[f32; 3]; 20] {
     let mut v = [[0.0; 3]; 20];
     let base = radians.cos();
     let height = radians.sin();
@@ -39,7 +123,7 @@ pub fn vertices(radians: f32, weight: f32) -> [[f32; 3]; 20] {
     b[2] = wx / ratio;
     b[3] = wx / height.abs();
     h[0] = wy;
-    h[1] = -(height + 3.0 * wy);
+    h[1] = -(height + stem_offset * wy);
     h[2] = wy / 2.0 * ratio;
     h[3] = wy / base.abs();
     for i in [1, 5, 9, 13, 16, 18] {
@@ -47,12 +131,12 @@ pub fn vertices(radians: f32, weight: f32) -> [[f32; 3]; 20] {
     }
     v[2][0] = base + b[3];
     v[3][0] = base;
-    v[6][0] = base + b[3] + b[2];
-    v[7][0] = base + b[2];
-    v[10][0] = base + b[3] + 2.0 * b[2];
-    v[11][0] = base + 2.0 * b[2];
-    v[14][0] = base + b[3] + 3.0 * b[2];
-    v[15][0] = base + 3.0 * b[2];
+    v[6][0] = base + b[3] + (stem_offset - 2.0) * b[2];
+    v[7][0] = base + (stem_offset - 2.0) * b[2];
+    v[10][0] = base + b[3] + (stem_offset - 1.0) * b[2];
+    v[11][0] = base + (stem_offset - 1.0) * b[2];
+    v[14][0] = base + b[3] + stem_offset * b[2];
+    v[15][0] = base + stem_offset * b[2];
     for i in 4..8 {
         v[i][1] = height + h[1] + 2.0 * h[0]
     }
@@ -83,14 +167,23 @@ pub fn quad_vertices(quadrant: usize, radians: f32, weight: f32) -> [[f32; 3]; 2
 }
 
 pub fn glyph_vertices(radians: f32, weight: f32, flip: bool) -> [[f32; 3]; 40] {
+    glyph_vertices_with_stem(radians, weight, flip, STEM_OFFSET)
+}
+
+pub fn glyph_vertices_with_stem(
+    radians: f32,
+    weight: f32,
+    flip: bool,
+    stem_offset: f32,
+) -> [[f32; 3]; 40] {
     let quads = quadrants(radians);
     let mut sides = [quads[0], quads[1]];
     if flip {
         sides[0] = quads[2];
         sides[1] = quads[3];
     }
-    let a = vertices(sides[0], weight);
-    let b = vertices(sides[1], weight);
+    let a = vertices_with_stem(sides[0], weight, stem_offset);
+    let b = vertices_with_stem(sides[1], weight, stem_offset);
     let mut c: [[f32; 3]; 40] = [[0.0; 3]; 40];
     for i in 0..a.len() {
         c[i] = a[i];
@@ -178,6 +271,13 @@ pub fn double_section_select(u: Numeral) -> Vec<u32> {
     v
 }
 
+pub fn glyph_mesh(u: Numeral, radians: f32, weight: f32, flip: bool) -> Mesh {
+    Mesh::new(
+        glyph_vertices(radians, weight, flip).to_vec(),
+        double_section_select(u),
+    )
+}
+
 pub fn y_center(v: [f32; 3], radians: f32, flip: bool) -> [f32; 3] {
     let st = radians.sin();
     let mut sn = -0.5 * st;
@@ -0,0 +1,56 @@
+use crate::mesh::Mesh;
+use crate::style::GlyphStyle;
+use crate::unit_glyph::double_section_select;
+use crate::Numeral;
+
+// Anchoring rules for a composed numeral:
+// - the glyph body is centered on the origin with `y_center`
+// - the diacritic is scaled by DIACRITIC_SCALE and centered on x = 0
+// - the diacritic caps the apex of the glyph, DIACRITIC_GAP away from it:
+//   above the glyph normally, below it when flipped
+// The 40 glyph vertices come first, followed by the diacritic vertices.
+pub const DIACRITIC_SCALE: f32 = 0.3;
+pub const DIACRITIC_GAP: f32 = 0.1;
+pub const GLYPH_VERTEX_COUNT: usize = 40;
+
+pub fn diacritic_offset(u: Numeral, radians: f32, flip: bool, sample_rate: usize) -> [f32; 2] {
+    GlyphStyle::with(radians, 0.0, flip, sample_rate).diacritic_offset(u.diacritic())
+}
+
+pub fn numeral_mesh(u: Numeral, radians: f32, weight: f32, flip: bool, sample_rate: usize) -> Mesh {
+    GlyphStyle::with(radians, weight, flip, sample_rate).numeral_mesh(u)
+}
+
+pub fn numeral_vertices(
+    u: Numeral,
+    radians: f32,
+    weight: f32,
+    flip: bool,
+    sample_rate: usize,
+) -> Vec<[f32; 3]> {
+    numeral_mesh(u, radians, weight, flip, sample_rate).vertices
+}
+
+pub fn numeral_vertices2d(
+    u: Numeral,
+    radians: f32,
+    weight: f32,
+    flip: bool,
+    sample_rate: usize,
+) -> Vec<[f32; 2]> {
+    numeral_mesh(u, radians, weight, flip, sample_rate).to_2d()
+}
+
+pub fn numeral_indices(u: Numeral, sample_rate: usize) -> Vec<u32> {
+    let mut idx = double_section_select(u);
+    // the same raised sample rate `numeral_mesh` draws with
+    let d = u.diacritic();
+    let sample_rate = GlyphStyle::with(0.0, 0.0, false, sample_rate).sample_rate(d);
+    idx.extend(
+        d.indices(sample_rate)
+            .unwrap_or_else(|e| panic!("{}", e))
+            .iter()
+            .map(|i| i + GLYPH_VERTEX_COUNT as u32),
+    );
+    idx
+}
@@ -0,0 +1,87 @@
+use crate::constants::constants::*;
+use crate::Numeral;
+
+// Relations read as "a <relation> b", indexed by the offset of b's element
+// from a's along the generating cycle.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Relation {
+    Same,
+    Generates,
+    GeneratedBy,
+    Overcomes,
+    OvercomeBy,
+}
+
+impl Relation {
+    pub fn index(&self) -> u8 {
+        *self as u8
+    }
+    pub fn name(&self) -> &'static str {
+        WU_XING_RELATIONS[self.index() as usize]
+    }
+    pub fn name_cn(&self) -> &'static str {
+        WU_XING_RELATIONS_CN[self.index() as usize]
+    }
+}
+
+pub fn relation(a: Numeral, b: Numeral) -> Relation {
+    match (b.generating_index() + 5 - a.generating_index()) % 5 {
+        0 => Relation::Same,
+        1 => Relation::Generates,
+        2 => Relation::Overcomes,
+        3 => Relation::OvercomeBy,
+        4 => Relation::GeneratedBy,
+        _ => panic!("Can only index vals 0 - 4"),
+    }
+}
+
+// Maps a generating index back to the diacritic tier carrying that element.
+pub fn diacritic_index(generating_index: u8) -> u8 {
+    match GENERATING_INDECES
+        .iter()
+        .position(|&g| g == generating_index)
+    {
+        Some(d) => d as u8,
+        None => panic!("Can only index vals 0 - 4"),
+    }
+}
+
+pub fn element(generating_index: u8) -> &'static str {
+    ELEMENTS[diacritic_index(generating_index) as usize]
+}
+
+pub fn element_cn(generating_index: u8) -> &'static str {
+    ELEMENTS_CN[generating_index as usize]
+}
+
+pub fn produced_index(u: Numeral) -> u8 {
+    (u.generating_index() + 1) % 5
+}
+
+pub fn producing_index(u: Numeral) -> u8 {
+    (u.generating_index() + 4) % 5
+}
+
+pub fn controlled_index(u: Numeral) -> u8 {
+    (u.generating_index() + 2) % 5
+}
+
+pub fn controlling_index(u: Numeral) -> u8 {
+    (u.generating_index() + 3) % 5
+}
+
+pub fn produces(u: Numeral) -> &'static str {
+    element(produced_index(u))
+}
+
+pub fn produced_by(u: Numeral) -> &'static str {
+    element(producing_index(u))
+}
+
+pub fn controls(u: Numeral) -> &'static str {
+    element(controlled_index(u))
+}
+
+pub fn controlled_by(u: Numeral) -> &'static str {
+    element(controlling_index(u))
+}
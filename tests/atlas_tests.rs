@@ -0,0 +1,181 @@
+#[cfg(test)]
+mod atlas_tests {
+    use hyxos_numerals::atlas::*;
+    use hyxos_numerals::pick::tight_bounds;
+    use hyxos_numerals::style::GlyphStyle;
+    use hyxos_numerals::typeset::*;
+    use hyxos_numerals::*;
+
+    #[test]
+    fn test_sheet() {
+        let opts = AtlasOptions::default();
+        let atlas = build_atlas(&GlyphStyle::default(), &opts);
+        assert_eq!((atlas.image.width, atlas.image.height), (12 * 64, 5 * 64));
+        assert_eq!(atlas.glyphs.len(), 60);
+        let png = atlas.to_png();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(u32::from_be_bytes(png[16..20].try_into().unwrap()), 768);
+        assert_eq!(u32::from_be_bytes(png[20..24].try_into().unwrap()), 320);
+        for (i, g) in atlas.glyphs.iter().enumerate() {
+            assert_eq!(g.numeral, Numeral::new(i as u8));
+            let [x, y, w, h] = g.rect;
+            let (cx, cy) = ((i % 12) * 64, (i / 12) * 64);
+            // the rect keeps clear of the padding
+            assert!(x >= cx + opts.padding && y >= cy + opts.padding);
+            assert!(x + w <= cx + 64 - opts.padding && y + h <= cy + 64 - opts.padding);
+            assert!(w > 0 && h > 0);
+            let size = [768.0, 320.0, 768.0, 320.0];
+            let px = [x, y, x + w, y + h];
+            for k in 0..4 {
+                assert!((g.uv[k] * size[k] - px[k] as f32).abs() < 1e-3);
+            }
+        }
+        // every inked pixel belongs to the rect of its cell
+        let mut inked = 0;
+        for y in 0..320 {
+            for x in 0..768 {
+                if atlas.image.get(x, y)[3] == 0 {
+                    continue;
+                }
+                inked += 1;
+                let g = &atlas.glyphs[(y / 64) * 12 + x / 64];
+                let [rx, ry, rw, rh] = g.rect;
+                assert!(x >= rx && x < rx + rw && y >= ry && y < ry + rh);
+            }
+        }
+        assert!(inked > 0);
+    }
+
+    #[test]
+    fn test_cell_size_and_order() {
+        let opts = AtlasOptions {
+            cell: [40, 56],
+            padding: 6,
+            columns: 8,
+            ordering: SortScheme::Natural,
+            ..Default::default()
+        };
+        let atlas = build_atlas(&GlyphStyle::default(), &opts);
+        assert_eq!((atlas.image.width, atlas.image.height), (8 * 40, 8 * 56));
+        let order: Vec<Numeral> = atlas.glyphs.iter().map(|g| g.numeral).collect();
+        assert_eq!(order, Set::new().nat_sort().values());
+        for (i, g) in atlas.glyphs.iter().enumerate() {
+            let [x, y, w, h] = g.rect;
+            let (cx, cy) = ((i % 8) * 40, (i / 8) * 56);
+            assert!(x >= cx + 6 && y >= cy + 6);
+            assert!(x + w <= cx + 34 && y + h <= cy + 50);
+        }
+        // the widest or tallest glyph fills the space inside the padding
+        let widest = atlas.glyphs.iter().map(|g| g.rect[2]).max().unwrap();
+        let tallest = atlas.glyphs.iter().map(|g| g.rect[3]).max().unwrap();
+        assert!(widest >= 27 || tallest >= 43);
+    }
+
+    #[test]
+    fn test_metrics_match_layout() {
+        let style = GlyphStyle::default();
+        let atlas = build_atlas(&style, &AtlasOptions::default());
+        let s = atlas.scale;
+        let u = Uint::new(3 * 3600 + 41 * 60 + 22);
+        let opts = LayoutOptions::default();
+        let layout = layout(&u, &opts, &style);
+        let baseline = baseline(0, &opts);
+        let mut pen = 0.0;
+        for p in &layout.glyphs {
+            let g = atlas.get(&p.numeral.encoding()).unwrap();
+            assert!((g.advance / s + opts.spacing - p.advance).abs() < 1e-4);
+            // drawing the sprite at the pen plus its bearing lands on the ink
+            let [x0, _, _, y1] = tight_bounds(&style.numeral_mesh(p.numeral)).unwrap();
+            let left = (p.origin[0] + x0 - pen) * s;
+            let top = (p.origin[1] + y1 - baseline) * s;
+            assert!(g.bearing[0] <= left + 1e-3 && left - g.bearing[0] < 1.0);
+            assert!(g.bearing[1] >= top - 1e-3 && g.bearing[1] - top < 1.0);
+            pen += p.advance;
+        }
+    }
+
+    #[test]
+    fn test_json() {
+        let atlas = build_atlas(&GlyphStyle::default(), &AtlasOptions::default());
+        let json = atlas.to_json();
+        // the hand written JSON matches what serde writes
+        #[cfg(feature = "serde")]
+        assert_eq!(serde_json::to_string(&atlas).unwrap(), json);
+        let v: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(v["width"], 768);
+        assert_eq!(v["height"], 320);
+        assert_eq!(v["cell"], serde_json::json!([64, 64]));
+        assert!(v.get("image").is_none());
+        let glyphs = v["glyphs"].as_object().unwrap();
+        assert_eq!(glyphs.len(), 60);
+        for g in &atlas.glyphs {
+            let e = &glyphs[&g.numeral.encoding()];
+            assert_eq!(e["value"], g.numeral.u());
+            assert_eq!(e["rect"], serde_json::json!(g.rect));
+            assert_eq!(e["advance"].as_f64().unwrap() as f32, g.advance);
+            for k in 0..4 {
+                assert_eq!(e["uv"][k].as_f64().unwrap() as f32, g.uv[k]);
+            }
+            for k in 0..2 {
+                assert_eq!(e["bearing"][k].as_f64().unwrap() as f32, g.bearing[k]);
+            }
+        }
+        // keys follow the sheet
+        let natural = build_atlas(
+            &GlyphStyle::default(),
+            &AtlasOptions {
+                ordering: SortScheme::Natural,
+                ..Default::default()
+            },
+        );
+        let json = natural.to_json();
+        let positions: Vec<usize> = Set::new()
+            .nat_sort()
+            .values()
+            .iter()
+            .map(|u| json.find(&format!("\"{}\":", u.encoding())).unwrap())
+            .collect();
+        assert!(positions.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_ron() {
+        let atlas = build_atlas(&GlyphStyle::default(), &AtlasOptions::default());
+        let ron = ron::to_string(&atlas).unwrap();
+        assert!(ron.starts_with("(width:768,height:320,cell:(64,64),scale:"));
+        let v: ron::Value = ron::from_str(&ron).unwrap();
+        let ron::Value::Map(fields) = v else {
+            panic!("not a struct")
+        };
+        let glyphs = &fields[&ron::Value::String("glyphs".to_string())];
+        let ron::Value::Map(glyphs) = glyphs else {
+            panic!("not a map")
+        };
+        assert_eq!(glyphs.len(), 60);
+        let first = &atlas.glyphs[0];
+        let [x, y, w, h] = first.rect;
+        assert!(ron.contains(&format!(
+            "\"{}\":(value:{},rect:({},{},{},{}),",
+            first.numeral.encoding(),
+            first.numeral.u(),
+            x,
+            y,
+            w,
+            h
+        )));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cell size leaves no room inside the padding!")]
+    fn test_cell_too_small() {
+        build_atlas(
+            &GlyphStyle::default(),
+            &AtlasOptions {
+                cell: [8, 64],
+                padding: 4,
+                ..Default::default()
+            },
+        );
+    }
+}
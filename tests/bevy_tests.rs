@@ -0,0 +1,96 @@
+#![cfg(feature = "bevy")]
+
+mod bevy_tests {
+    use bevy_mesh::{Indices, VertexAttributeValues};
+    use hyxos_numerals::bevy::*;
+    use hyxos_numerals::style::GlyphStyle;
+    use hyxos_numerals::typeset::*;
+    use hyxos_numerals::unit_numeral::numeral_mesh;
+    use hyxos_numerals::*;
+    use std::f32::consts::PI;
+
+    fn positions(m: &BevyMesh) -> Vec<[f32; 3]> {
+        match m.attribute(BevyMesh::ATTRIBUTE_POSITION) {
+            Some(VertexAttributeValues::Float32x3(v)) => v.clone(),
+            _ => panic!("missing positions"),
+        }
+    }
+
+    fn colors(m: &BevyMesh) -> Vec<[f32; 4]> {
+        match m.attribute(BevyMesh::ATTRIBUTE_COLOR) {
+            Some(VertexAttributeValues::Float32x4(v)) => v.clone(),
+            _ => panic!("missing colors"),
+        }
+    }
+
+    fn indices(m: &BevyMesh) -> Vec<u32> {
+        match m.indices() {
+            Some(Indices::U32(i)) => i.clone(),
+            _ => panic!("missing indices"),
+        }
+    }
+
+    #[test]
+    fn test_numeral_mesh() {
+        let opts = BevyMeshOptions::default();
+        for u in Set::new().values() {
+            let m = numeral_bevy_mesh(u, &GlyphStyle::with(PI / 3.0, 0.1, false, 16), &opts);
+            let source = numeral_mesh(u, PI / 3.0, 0.1, false, 16);
+            assert_eq!(positions(&m), source.vertices);
+            assert_eq!(m.count_vertices(), source.vertices.len());
+            let idx = indices(&m);
+            assert_eq!(idx.len(), source.indices.len());
+            // every triangle faces the default camera
+            for t in idx.chunks_exact(3) {
+                let [a, b, c] = [t[0], t[1], t[2]].map(|i| source.vertices[i as usize]);
+                assert!((b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]) > 0.0);
+            }
+            match m.attribute(BevyMesh::ATTRIBUTE_NORMAL) {
+                Some(VertexAttributeValues::Float32x3(n)) => {
+                    assert!(n.iter().all(|n| *n == [0.0, 0.0, 1.0]))
+                }
+                _ => panic!("missing normals"),
+            }
+            assert!(m.attribute(BevyMesh::ATTRIBUTE_UV_0).is_some());
+            let c = colors(&m);
+            assert!(c.iter().all(|x| *x == c[0]));
+        }
+        let yellow = colors(&numeral_bevy_mesh(
+            Numeral::new(0),
+            &GlyphStyle::with(PI / 3.0, 0.1, false, 16),
+            &opts,
+        ));
+        assert_eq!(yellow[0], [1.0, 1.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_uint_mesh() {
+        let u = Uint::new(60usize.pow(2) * 12 + 60 * 25 + 7);
+        let layout_opts = LayoutOptions {
+            separator: Some(Separator::Colon),
+            ..LayoutOptions::default()
+        };
+        let opts = BevyMeshOptions::default();
+        let m = uint_bevy_mesh(
+            &u,
+            &layout_opts,
+            &GlyphStyle::with(PI / 3.0, 0.1, false, 16),
+            &opts,
+        );
+        let flat = typeset_mesh(
+            &u,
+            &layout_opts,
+            &GlyphStyle::with(PI / 3.0, 0.1, false, 16),
+        );
+        assert_eq!(positions(&m), flat.vertices);
+        assert_eq!(indices(&m).len(), flat.indices.len());
+        let c = colors(&m);
+        assert_eq!(c.len(), flat.vertices.len());
+        // two colons of two squares each close the vertex buffer in black
+        assert!(c[c.len() - 16..].iter().all(|c| *c == [0.0, 0.0, 0.0, 1.0]));
+        let tiers: std::collections::HashSet<_> =
+            u.values().iter().map(|n| n.diacritic_index()).collect();
+        assert!(tiers.len() > 1);
+        assert!(c.iter().any(|x| *x != c[0]));
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod branch_tests {
+    use hyxos_numerals::branches::*;
+    use hyxos_numerals::*;
+
+    // The ta tier numeral carrying the given branch.
+    fn branch(cn: &str) -> Numeral {
+        Set::new()
+            .values()
+            .into_iter()
+            .find(|n| n.earthly_branch() == cn)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_pair_relations() {
+        assert_eq!(
+            pair_relations(branch("子"), branch("午")),
+            vec![BranchRelation::Clash]
+        );
+        assert_eq!(
+            pair_relations(branch("子"), branch("丑")),
+            vec![BranchRelation::Harmony]
+        );
+        assert_eq!(
+            pair_relations(branch("寅"), branch("亥")),
+            vec![BranchRelation::Harmony, BranchRelation::Destruction]
+        );
+        assert_eq!(
+            pair_relations(branch("寅"), branch("巳")),
+            vec![BranchRelation::Punishment, BranchRelation::Harm]
+        );
+        assert_eq!(
+            pair_relations(branch("子"), branch("卯")),
+            vec![BranchRelation::Punishment]
+        );
+        assert_eq!(
+            pair_relations(branch("卯"), branch("午")),
+            vec![BranchRelation::Destruction]
+        );
+        assert_eq!(
+            pair_relations(branch("午"), branch("午")),
+            vec![BranchRelation::Punishment]
+        );
+        assert_eq!(pair_relations(branch("子"), branch("子")), vec![]);
+        assert_eq!(
+            pair_relations(branch("酉"), branch("戌")),
+            vec![BranchRelation::Harm]
+        );
+        assert!(pair_relations(branch("子"), branch("寅")).is_empty());
+    }
+
+    #[test]
+    fn test_pair_relations_are_symmetric() {
+        let set = Set::new().values();
+        for a in &set {
+            for b in &set {
+                assert_eq!(pair_relations(*a, *b), pair_relations(*b, *a));
+            }
+        }
+        let clashes = set.iter().filter(|b| is_clash(set[1], **b)).count();
+        assert_eq!(clashes, 5);
+    }
+
+    #[test]
+    fn test_triple_relations() {
+        assert_eq!(
+            triple_relations(branch("申"), branch("子"), branch("辰")),
+            vec![BranchRelation::ThreeHarmony]
+        );
+        assert_eq!(
+            triple_relations(branch("亥"), branch("子"), branch("丑")),
+            vec![BranchRelation::Direction]
+        );
+        assert_eq!(
+            triple_relations(branch("寅"), branch("巳"), branch("申")),
+            vec![BranchRelation::Punishment]
+        );
+        assert!(triple_relations(branch("子"), branch("子"), branch("辰")).is_empty());
+        assert_eq!(BranchRelation::ThreeHarmony.name(), "three harmony");
+        assert_eq!(BranchRelation::Clash.name_cn(), "冲");
+    }
+}
@@ -0,0 +1,36 @@
+// Helpers shared by the integration tests
+
+// Width, height and pixel bytes of a PNG, rows unfiltered. The encoder only
+// writes filter type 0, so every row's filter byte is checked to be 0.
+pub fn decode_png(png: &[u8]) -> (usize, usize, Vec<u8>) {
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    let mut pos = 8;
+    let (mut w, mut h, mut channels, mut idat) = (0, 0, 0, Vec::new());
+    while pos < png.len() {
+        let len = u32::from_be_bytes(png[pos..pos + 4].try_into().unwrap()) as usize;
+        let data = &png[pos + 8..pos + 8 + len];
+        match &png[pos + 4..pos + 8] {
+            b"IHDR" => {
+                w = u32::from_be_bytes(data[0..4].try_into().unwrap()) as usize;
+                h = u32::from_be_bytes(data[4..8].try_into().unwrap()) as usize;
+                channels = match data[9] {
+                    0 => 1,
+                    2 => 3,
+                    6 => 4,
+                    c => panic!("unexpected color type {}", c),
+                };
+            }
+            b"IDAT" => idat.extend(data),
+            _ => (),
+        }
+        pos += len + 12;
+    }
+    let raw = miniz_oxide::inflate::decompress_to_vec_zlib(&idat).unwrap();
+    assert_eq!(raw.len(), (w * channels + 1) * h);
+    let mut pixels = Vec::new();
+    for row in raw.chunks(w * channels + 1) {
+        assert_eq!(row[0], 0);
+        pixels.extend(&row[1..]);
+    }
+    (w, h, pixels)
+}
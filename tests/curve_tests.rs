@@ -0,0 +1,258 @@
+#[cfg(test)]
+mod curve_tests {
+    use hyxos_numerals::curve::*;
+    use hyxos_numerals::font::*;
+    use hyxos_numerals::pdf::*;
+    use hyxos_numerals::style::GlyphStyle;
+    use hyxos_numerals::svg::*;
+    use hyxos_numerals::unit_diacritic::*;
+    use hyxos_numerals::*;
+    use ttf_parser::{Face, GlyphId, OutlineBuilder, Tag};
+
+    fn close(a: [f32; 2], b: [f32; 2]) -> bool {
+        (a[0] - b[0]).abs() < 1e-5 && (a[1] - b[1]).abs() < 1e-5
+    }
+
+    fn distance(a: [f32; 2], b: [f32; 2]) -> f32 {
+        ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt()
+    }
+
+    fn segment_distance(p: [f32; 2], a: [f32; 2], b: [f32; 2]) -> f32 {
+        let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+        let len = dx * dx + dy * dy;
+        if len == 0.0 {
+            return distance(p, a);
+        }
+        let t = (((p[0] - a[0]) * dx + (p[1] - a[1]) * dy) / len).clamp(0.0, 1.0);
+        distance(p, [a[0] + t * dx, a[1] + t * dy])
+    }
+
+    #[test]
+    fn test_curves_match_samples() {
+        let sr = 16;
+        for d in &Diacritic::ALL[1..] {
+            let curve = DiacriticCurve::new(*d, DIACRITIC_RADIUS);
+            let v = d.vertices(sr).unwrap();
+            for i in 0..=sr {
+                let t = i as f32 / sr as f32;
+                let [u, l] = [v[2 * i], v[2 * i + 1]].map(|p| [p[0], p[1]]);
+                assert!(close(curve.upper(t), u), "{:?} {} {:?}", d, i, u);
+                assert!(close(curve.lower(t), l), "{:?} {} {:?}", d, i, l);
+            }
+        }
+        let ta = DiacriticCurve::new(Diacritic::Ta, DIACRITIC_RADIUS);
+        let r = (DIACRITIC_RADIUS / 2.0).sqrt();
+        for i in 0..=10 {
+            let p = ta.point(i as f32 / 10.0);
+            assert!((distance(p, [0.0, 0.0]) - r).abs() < 1e-5);
+        }
+        for p in diacritic_vertices(0, sr).unwrap() {
+            assert!(distance([p[0], p[1]], [0.0, 0.0]) <= r + 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_curve_diacritic() {
+        for d in Diacritic::ALL {
+            assert_eq!(DiacriticCurve::new(d, DIACRITIC_RADIUS).diacritic(), d);
+        }
+    }
+
+    #[test]
+    fn test_adaptive_sampling() {
+        for d in Diacritic::ALL {
+            let curve = DiacriticCurve::new(d, DIACRITIC_RADIUS);
+            let coarse = curve.sample(1e-2);
+            let fine = curve.sample(1e-4);
+            // jo is straight between its corners
+            if d == Diacritic::Jo {
+                assert_eq!(fine.len(), coarse.len());
+            } else {
+                assert!(fine.len() > coarse.len());
+            }
+            assert_eq!((coarse[0], *fine.last().unwrap()), (0.0, 1.0));
+            assert!(fine.windows(2).all(|w| w[0] < w[1]));
+            // corners are always sampled
+            for c in curve.corners() {
+                assert!(coarse.contains(&c));
+            }
+            // segment midpoints stay near the curve
+            for w in fine.windows(2) {
+                let tm = (w[0] + w[1]) / 2.0;
+                let (a, b) = (curve.upper(w[0]), curve.upper(w[1]));
+                let m = [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0];
+                assert!(distance(m, curve.upper(tm)) < 1e-3);
+            }
+            let mesh = curve.mesh(1e-3);
+            assert!(mesh.is_valid());
+            assert_eq!(mesh.vertices.len(), 2 * curve.sample(1e-3).len());
+        }
+    }
+
+    #[test]
+    fn test_polygon_is_ccw() {
+        for d in Diacritic::ALL {
+            let p = DiacriticCurve::new(d, DIACRITIC_RADIUS).polygon(1e-3);
+            let area: f32 = (0..p.len())
+                .map(|i| {
+                    let (a, b) = (p[i], p[(i + 1) % p.len()]);
+                    a[0] * b[1] - b[0] * a[1]
+                })
+                .sum();
+            assert!(area > 0.0, "{:?}", d);
+        }
+    }
+
+    #[test]
+    fn test_bezier_error() {
+        for tolerance in [1e-2, 1e-3] {
+            for d in Diacritic::ALL {
+                let curve = DiacriticCurve::new(d, DIACRITIC_RADIUS);
+                let path = curve.beziers(tolerance);
+                let dense = curve.polygon(tolerance / 20.0);
+                for w in path.segments.windows(2) {
+                    assert!(close(w[0][3], w[1][0]));
+                }
+                let first = path.segments[0][0];
+                assert!(close(path.segments.last().unwrap()[3], first));
+                for c in &path.segments {
+                    for k in 0..=8 {
+                        let p = cubic_point(c, k as f32 / 8.0);
+                        let near = (0..dense.len())
+                            .map(|i| segment_distance(p, dense[i], dense[(i + 1) % dense.len()]))
+                            .fold(f32::MAX, f32::min);
+                        assert!(near < 2.0 * tolerance, "{:?} {}", d, near);
+                    }
+                }
+                let quads = path.to_quadratics(tolerance);
+                assert!(quads.len() >= path.segments.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_transform_matches_placement() {
+        for style in [
+            GlyphStyle::regular(),
+            GlyphStyle::italic(),
+            GlyphStyle::condensed(),
+        ] {
+            for d in &Diacritic::ALL[1..] {
+                let d = *d;
+                let m = style.diacritic_transform(d);
+                let placed = style.place_diacritic(d, &style.diacritic_mesh(d));
+                for (p, q) in style
+                    .diacritic_mesh(d)
+                    .vertices
+                    .iter()
+                    .zip(&placed.vertices)
+                {
+                    let x = m[0][0] * p[0] + m[0][1] * p[1] + m[0][2];
+                    let y = m[1][0] * p[0] + m[1][1] * p[1] + m[1][2];
+                    assert!(close([x, y], [q[0], q[1]]));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_svg_curves() {
+        let u = Numeral::new(13);
+        let plain = numeral_svg(u, &SvgStyle::default());
+        let curved = numeral_svg(
+            u,
+            &SvgStyle {
+                curve_tolerance: Some(1e-3),
+                ..Default::default()
+            },
+        );
+        assert!(!plain.contains('C'));
+        assert!(curved.contains('C'));
+        assert!(curved.len() < plain.len());
+    }
+
+    struct Quads(usize);
+
+    impl OutlineBuilder for Quads {
+        fn move_to(&mut self, _x: f32, _y: f32) {}
+        fn line_to(&mut self, _x: f32, _y: f32) {}
+        fn quad_to(&mut self, _x1: f32, _y1: f32, _x: f32, _y: f32) {
+            self.0 += 1;
+        }
+        fn curve_to(&mut self, _x1: f32, _y1: f32, _x2: f32, _y2: f32, _x: f32, _y: f32) {}
+        fn close(&mut self) {}
+    }
+
+    // The bounds stored in the glyph header; ttf-parser recomputes its own
+    // from every point, control points included
+    fn header_bbox(face: &Face, id: u16) -> [i16; 4] {
+        let raw = face.raw_face();
+        let loca = raw.table(Tag::from_bytes(b"loca")).unwrap();
+        let glyf = raw.table(Tag::from_bytes(b"glyf")).unwrap();
+        let i = 4 * id as usize;
+        let offset = u32::from_be_bytes(loca[i..i + 4].try_into().unwrap()) as usize;
+        [0, 1, 2, 3].map(|k| {
+            let j = offset + 2 + 2 * k;
+            i16::from_be_bytes([glyf[j], glyf[j + 1]])
+        })
+    }
+
+    #[test]
+    fn test_font_curves() {
+        let opts = FontOptions {
+            curve_tolerance: Some(1e-3),
+            ..Default::default()
+        };
+        let font = build_font(&opts);
+        let face = Face::parse(&font, 0).unwrap();
+        let plain = build_font(&FontOptions::default());
+        let plain = Face::parse(&plain, 0).unwrap();
+        for id in 1..61 {
+            let mut q = Quads(0);
+            face.outline_glyph(GlyphId(id), &mut q).unwrap();
+            assert!(q.0 > 0);
+            // the curved diacritic lands where the traced one did
+            let (bbox, p) = (header_bbox(&face, id), header_bbox(&plain, id));
+            for k in 0..4 {
+                assert!((bbox[k] - p[k]).abs() <= 2, "{} {:?} {:?}", id, bbox, p);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = numeral_pdf(
+            Numeral::new(59),
+            &GlyphStyle::default(),
+            &PdfOptions::default(),
+        );
+        let text = String::from_utf8(pdf.clone()).unwrap();
+        assert!(text.starts_with("%PDF-1.4\n"));
+        assert!(text.ends_with("%%EOF\n"));
+        assert!(text.contains(" c\n"));
+        let start: usize = text.lines().rev().nth(1).unwrap().parse().unwrap();
+        assert!(text[start..].starts_with("xref\n0 5\n"));
+        for (i, line) in text[start..].lines().skip(3).take(4).enumerate() {
+            let offset: usize = line[..10].parse().unwrap();
+            assert!(text[offset..].starts_with(&format!("{} 0 obj", i + 1)));
+        }
+        let length: usize = text
+            .split("/Length ")
+            .nth(1)
+            .and_then(|s| s.split(' ').next())
+            .unwrap()
+            .parse()
+            .unwrap();
+        let stream = text.find("stream\n").unwrap() + 7;
+        assert!(text[stream + length..].starts_with("endstream"));
+        let sampled = numeral_pdf(
+            Numeral::new(59),
+            &GlyphStyle::default(),
+            &PdfOptions {
+                curve_tolerance: None,
+                ..Default::default()
+            },
+        );
+        assert!(!String::from_utf8(sampled).unwrap().contains(" c\n"));
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod diacritic_tests {
+    use hyxos_numerals::mesh::MeshIssue;
+    use hyxos_numerals::unit_diacritic::*;
+    use hyxos_numerals::*;
+
+    #[test]
+    fn test_indexing() {
+        for (i, d) in Diacritic::ALL.iter().enumerate() {
+            assert_eq!(d.index() as usize, i);
+            assert_eq!(Diacritic::try_from(i as u8), Ok(*d));
+        }
+        assert_eq!(Diacritic::try_from(5), Err(DiacriticError::InvalidIndex(5)));
+        for u in Set::new().values() {
+            assert_eq!(u.diacritic().index(), u.diacritic_index());
+        }
+        assert_eq!(Numeral::new(59).diacritic(), Diacritic::Wu);
+    }
+
+    #[test]
+    fn test_sample_rates() {
+        for d in Diacritic::ALL {
+            assert_eq!(
+                d.mesh(0),
+                Err(DiacriticError::SampleRateTooLow {
+                    diacritic: d,
+                    sample_rate: 0,
+                    min: d.min_sample_rate(),
+                })
+            );
+            assert!(d.indices(0).is_err());
+        }
+        assert!(Diacritic::Ta.vertices(2).is_err());
+        assert!(Diacritic::Shey.vertices(1).is_ok());
+        let e = Diacritic::Ta.vertices(1).unwrap_err();
+        assert_eq!(e.to_string(), "Ta needs a sample rate of at least 3, got 1");
+    }
+
+    #[test]
+    fn test_buffers_match() {
+        for d in Diacritic::ALL {
+            for sr in d.min_sample_rate()..=64 {
+                let m = d.mesh(sr).unwrap();
+                assert_eq!(m.vertices.len(), d.vertex_count(sr));
+                assert_eq!(m.indices.len(), d.index_count(sr));
+                assert_eq!(
+                    *m.indices.iter().max().unwrap() as usize,
+                    m.vertices.len() - 1
+                );
+                // ta's stitch closes the fan with a sliver, nothing worse
+                assert!(m
+                    .validate()
+                    .iter()
+                    .all(|i| matches!(i, MeshIssue::Degenerate { .. })));
+            }
+        }
+        let ta = Diacritic::Ta.vertices(8).unwrap();
+        assert_eq!(ta[9], ta[1]);
+    }
+
+    #[test]
+    fn test_loose_functions_agree() {
+        for d in Diacritic::ALL {
+            let i = d.index();
+            assert_eq!(diacritic_vertices(i, 16), d.vertices(16));
+            assert_eq!(gen_diacritic_indices(i, 16), d.indices(16));
+            assert_eq!(diacritic_mesh(i, 16), d.mesh(16));
+        }
+    }
+
+    #[test]
+    fn test_loose_functions_report_errors() {
+        let err = DiacriticError::InvalidIndex(5);
+        assert_eq!(diacritic_vertices(5, 16), Err(err));
+        assert_eq!(diacritic_vertices_with_radius(5, 16, 0.5), Err(err));
+        assert_eq!(gen_diacritic_indices(5, 2), Err(err));
+        assert_eq!(diacritic_mesh(5, 16), Err(err));
+        let low = DiacriticError::SampleRateTooLow {
+            diacritic: Diacritic::Ta,
+            sample_rate: 2,
+            min: 3,
+        };
+        assert_eq!(diacritic_vertices(0, 2), Err(low));
+        assert_eq!(gen_diacritic_indices(0, 2), Err(low));
+    }
+}
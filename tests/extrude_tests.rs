@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod extrude_tests {
+    use hyxos_numerals::export::*;
+    use hyxos_numerals::extrude::*;
+    use hyxos_numerals::mesh::Mesh;
+    use hyxos_numerals::style::GlyphStyle;
+    use hyxos_numerals::*;
+    use std::f32::consts::PI;
+
+    const SQUARE: [[f32; 3]; 4] = [
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [1.0, 1.0, 0.0],
+        [0.0, 1.0, 0.0],
+    ];
+    // second triangle deliberately wound clockwise
+    const SQUARE_INDICES: [u32; 6] = [0, 1, 2, 0, 3, 2];
+
+    fn volume(m: &Mesh) -> f32 {
+        m.triangles()
+            .map(|[a, b, c]| {
+                (a[0] * (b[1] * c[2] - b[2] * c[1]) - a[1] * (b[0] * c[2] - b[2] * c[0])
+                    + a[2] * (b[0] * c[1] - b[1] * c[0]))
+                    / 6.0
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_extrude_square() {
+        let square = Mesh::new(SQUARE.to_vec(), SQUARE_INDICES.to_vec());
+        let solid = extrude(&square, 0.5);
+        assert_eq!(solid.vertices.len(), 8);
+        assert_eq!(solid.triangle_count(), 12);
+        assert!(check_watertight(&solid).is_watertight());
+        assert!((volume(&solid) - 0.5).abs() < 1e-5);
+        assert!(!check_watertight(&square).is_watertight());
+        assert_eq!(check_watertight(&square).flipped_edges, 1);
+    }
+
+    #[test]
+    fn test_numeral_solids_are_watertight() {
+        for u in Set::new().values() {
+            for flip in [false, true] {
+                let solid = numeral_solid(u, &GlyphStyle::with(PI / 3.0, 0.1, flip, 16), 0.2);
+                assert_eq!(check_watertight(&solid), Watertightness::default());
+                assert!(volume(&solid) > 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_exports() {
+        let solid = numeral_solid(
+            Numeral::new(33),
+            &GlyphStyle::with(PI / 3.0, 0.1, false, 16),
+            0.2,
+        );
+        let (v, idx) = (&solid.vertices, &solid.indices);
+        let tris = solid.triangle_count();
+
+        let obj = to_obj(&solid);
+        assert_eq!(obj.lines().filter(|l| l.starts_with("v ")).count(), v.len());
+        assert_eq!(obj.lines().filter(|l| l.starts_with("f ")).count(), tris);
+
+        let ascii = to_stl_ascii(&solid, "jotree");
+        assert!(ascii.starts_with("solid jotree\n"));
+        assert_eq!(ascii.matches("facet normal").count(), tris);
+
+        let binary = to_stl_binary(&solid);
+        assert_eq!(binary.len(), 84 + 50 * tris);
+        assert_eq!(
+            u32::from_le_bytes(binary[80..84].try_into().unwrap()) as usize,
+            tris
+        );
+
+        let glb = to_glb(&solid);
+        assert_eq!(&glb[..4], b"glTF");
+        assert_eq!(
+            u32::from_le_bytes(glb[8..12].try_into().unwrap()) as usize,
+            glb.len()
+        );
+        let json_len = u32::from_le_bytes(glb[12..16].try_into().unwrap()) as usize;
+        assert_eq!(&glb[16..20], b"JSON");
+        let json = std::str::from_utf8(&glb[20..20 + json_len]).unwrap();
+        assert!(json.contains(&format!("\"count\":{},\"type\":\"VEC3\"", v.len())));
+        assert!(json.contains(&format!("\"count\":{},\"type\":\"SCALAR\"", idx.len())));
+        let bin = 20 + json_len;
+        assert_eq!(&glb[bin + 4..bin + 8], b"BIN\0");
+        assert_eq!(glb.len() % 4, 0);
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod font_tests {
+    use hyxos_numerals::font::*;
+    use hyxos_numerals::style::GlyphStyle;
+    use ttf_parser::{Face, GlyphId, OutlineBuilder};
+
+    #[derive(Default)]
+    struct Counter {
+        contours: usize,
+        points: usize,
+    }
+
+    impl OutlineBuilder for Counter {
+        fn move_to(&mut self, _x: f32, _y: f32) {
+            self.contours += 1;
+            self.points += 1;
+        }
+        fn line_to(&mut self, _x: f32, _y: f32) {
+            self.points += 1;
+        }
+        fn quad_to(&mut self, _x1: f32, _y1: f32, _x: f32, _y: f32) {}
+        fn curve_to(&mut self, _x1: f32, _y1: f32, _x2: f32, _y2: f32, _x: f32, _y: f32) {}
+        fn close(&mut self) {}
+    }
+
+    fn checksum(b: &[u8]) -> u32 {
+        b.chunks(4).fold(0u32, |sum, c| {
+            let mut w = [0u8; 4];
+            w[..c.len()].copy_from_slice(c);
+            sum.wrapping_add(u32::from_be_bytes(w))
+        })
+    }
+
+    #[test]
+    fn test_font_round_trip() {
+        let opts = FontOptions::default();
+        let font = build_font(&opts);
+        assert_eq!(checksum(&font), 0xB1B0AFBA);
+        let face = Face::parse(&font, 0).unwrap();
+        assert_eq!(face.number_of_glyphs(), 61);
+        assert_eq!(face.units_per_em(), 1000);
+        assert!(face.ascender() > 0);
+        assert!(face.is_monospaced());
+        assert_eq!(face.glyph_index('\u{E000}'), Some(GlyphId(1)));
+        assert_eq!(face.glyph_index('\u{E03B}'), Some(GlyphId(60)));
+        assert_eq!(face.glyph_index('\u{E03C}'), None);
+        let advance = face.glyph_hor_advance(GlyphId(1)).unwrap();
+        for g in 1..61 {
+            let mut c = Counter::default();
+            let bbox = face.outline_glyph(GlyphId(g), &mut c).unwrap();
+            assert!(c.contours > 0);
+            assert!(c.points >= c.contours * 3);
+            assert!(bbox.x_max as u16 <= advance);
+            assert!(bbox.y_max <= face.ascender());
+            assert_eq!(face.glyph_hor_advance(GlyphId(g)), Some(advance));
+        }
+        let family = face
+            .names()
+            .into_iter()
+            .find(|n| n.name_id == ttf_parser::name_id::FAMILY)
+            .and_then(|n| n.to_string());
+        assert_eq!(family.as_deref(), Some("Hyxos Numerals"));
+    }
+
+    #[test]
+    fn test_supplementary_private_use_area() {
+        let opts = FontOptions {
+            first_codepoint: 0xF0000,
+            advance: Some(900),
+            glyph: GlyphStyle {
+                flip: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let font = build_font(&opts);
+        let face = Face::parse(&font, 0).unwrap();
+        assert_eq!(face.glyph_index('\u{F0000}'), Some(GlyphId(1)));
+        assert_eq!(face.glyph_index('\u{F003B}'), Some(GlyphId(60)));
+        assert_eq!(face.glyph_hor_advance(GlyphId(5)), Some(900));
+        assert!(face.descender() < 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rejects_codepoints_outside_private_use() {
+        build_font(&FontOptions {
+            first_codepoint: 0x41,
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Codepoints must lie within a Private Use Area!")]
+    fn test_rejects_codepoints_near_the_top() {
+        build_font(&FontOptions {
+            first_codepoint: u32::MAX - 10,
+            ..Default::default()
+        });
+    }
+}
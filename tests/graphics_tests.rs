@@ -0,0 +1,270 @@
+mod common;
+
+#[cfg(test)]
+mod graphics_tests {
+    use crate::common::decode_png;
+    use hyxos_numerals::graphics::*;
+    use hyxos_numerals::raster::*;
+    use hyxos_numerals::style::GlyphStyle;
+    use hyxos_numerals::typeset::LayoutOptions;
+    use hyxos_numerals::*;
+
+    // Pixels as RGB registers, None where nothing was painted
+    fn decode_sixel(s: &str) -> (usize, usize, Vec<Option<[u8; 3]>>) {
+        let body = s
+            .strip_prefix("\x1bP0;1;0q\"1;1;")
+            .and_then(|b| b.strip_suffix("\x1b\\"))
+            .unwrap();
+        let mut chars = body.chars().peekable();
+        let number = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+            let mut n = 0;
+            while let Some(d) = chars.peek().and_then(|c| c.to_digit(10)) {
+                n = n * 10 + d as usize;
+                chars.next();
+            }
+            n
+        };
+        let w = number(&mut chars);
+        assert_eq!(chars.next(), Some(';'));
+        let h = number(&mut chars);
+        let mut pixels = vec![None; w * h];
+        let mut registers = vec![[0u8; 3]; 256];
+        let (mut x, mut band, mut color) = (0, 0, 0);
+        while let Some(c) = chars.next() {
+            match c {
+                '#' => {
+                    color = number(&mut chars);
+                    if chars.peek() == Some(&';') {
+                        chars.next();
+                        assert_eq!(number(&mut chars), 2);
+                        for channel in registers[color].iter_mut() {
+                            assert_eq!(chars.next(), Some(';'));
+                            *channel = (number(&mut chars) * 255 / 100) as u8;
+                        }
+                    }
+                }
+                '$' => x = 0,
+                '-' => (x, band) = (0, band + 1),
+                '!' | '?'..='~' => {
+                    let (n, c) = if c == '!' {
+                        (number(&mut chars), chars.next().unwrap())
+                    } else {
+                        (1, c)
+                    };
+                    let bits = c as u8 - 63;
+                    for _ in 0..n {
+                        for dy in 0..6 {
+                            if bits & 1 << dy != 0 {
+                                pixels[(6 * band + dy) * w + x] = Some(registers[color]);
+                            }
+                        }
+                        x += 1;
+                    }
+                }
+                _ => panic!("unexpected {:?}", c),
+            }
+        }
+        (w, h, pixels)
+    }
+
+    fn decode_base64(s: &str) -> Vec<u8> {
+        let value = |c: u8| match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => panic!("not base64"),
+        } as u32;
+        let mut out = Vec::new();
+        for chunk in s.as_bytes().chunks(4) {
+            let n = chunk
+                .iter()
+                .take_while(|&&c| c != b'=')
+                .fold(0, |n, &c| n << 6 | value(c));
+            let len = chunk.iter().filter(|&&c| c != b'=').count();
+            let n = n << (6 * (4 - len));
+            out.extend(&[(n >> 16) as u8, (n >> 8) as u8, n as u8][..len - 1]);
+        }
+        out
+    }
+
+    // Control keys of the first command and the joined payload
+    fn decode_kitty(s: &str) -> (Vec<(String, String)>, Vec<u8>) {
+        let commands: Vec<&str> = s
+            .split("\x1b\\")
+            .filter(|c| !c.is_empty())
+            .map(|c| c.strip_prefix("\x1b_G").unwrap())
+            .collect();
+        let mut keys = Vec::new();
+        let mut payload = String::new();
+        for (i, c) in commands.iter().enumerate() {
+            let (control, data) = c.split_once(';').unwrap();
+            let pairs: Vec<(String, String)> = control
+                .split(',')
+                .map(|kv| {
+                    let (k, v) = kv.split_once('=').unwrap();
+                    (k.to_string(), v.to_string())
+                })
+                .collect();
+            let more = &pairs.iter().find(|(k, _)| k == "m").unwrap().1;
+            assert_eq!(more, if i + 1 < commands.len() { "1" } else { "0" });
+            assert!(data.len() <= 4096);
+            if i == 0 {
+                keys = pairs;
+            } else {
+                assert_eq!(pairs.len(), 1);
+            }
+            payload += data;
+        }
+        (keys, decode_base64(&payload))
+    }
+
+    fn key<'a>(keys: &'a [(String, String)], k: &str) -> Option<&'a str> {
+        keys.iter().find(|(x, _)| x == k).map(|(_, v)| v.as_str())
+    }
+
+    #[test]
+    fn test_sixel_round_trip() {
+        let style = GlyphStyle::default();
+        let transparent = ImageOptions {
+            raster: RasterOptions {
+                scale: 24.0,
+                foreground: [200, 40, 90, 255],
+                background: [0, 0, 0, 0],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        for opts in [ImageOptions::default(), transparent] {
+            let bmp = numeral_image(Numeral::new(27), &style, &opts);
+            let sixel = encode_sixel(&bmp);
+            assert_eq!(sixel, numeral_sixel(Numeral::new(27), &style, &opts));
+            let (w, h, pixels) = decode_sixel(&sixel);
+            assert_eq!((w, h), (bmp.width, bmp.height));
+            let rgb = bmp.rgb();
+            for (i, p) in pixels.iter().enumerate() {
+                match p {
+                    None => assert_eq!(bmp.pixels[i][3], 0),
+                    // registers only keep whole percents
+                    Some(c) => {
+                        for k in 0..3 {
+                            assert!((c[k] as i32 - rgb[i][k] as i32).abs() <= 3);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sixel_palette_limit() {
+        // a gradient with far more than 256 colors
+        let mut bmp = Bitmap::new(64, 20, [0, 0, 0, 255]);
+        for y in 0..20 {
+            for x in 0..64 {
+                bmp.pixels[y * 64 + x] = [(x * 4) as u8, (y * 12) as u8, 128, 255];
+            }
+        }
+        let sixel = encode_sixel(&bmp);
+        assert!(!sixel.contains("#256;"));
+        let (_, _, pixels) = decode_sixel(&sixel);
+        for (p, q) in pixels.iter().zip(bmp.rgb()) {
+            let p = p.unwrap();
+            for k in 0..3 {
+                assert!((p[k] as i32 - q[k] as i32).abs() <= 8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_kitty_round_trip() {
+        let style = GlyphStyle::default();
+        let opts = ImageOptions::default();
+        let u = Uint::new(7 * 3600 + 59);
+        let bmp = uint_image(&u, &LayoutOptions::default(), &style, &opts);
+        let png = KittyOptions::default();
+        let (keys, data) = decode_kitty(&encode_kitty(&bmp, &png));
+        assert_eq!(key(&keys, "f"), Some("100"));
+        assert_eq!(key(&keys, "a"), Some("T"));
+        assert_eq!(key(&keys, "c"), None);
+        let (w, h, pixels) = decode_png(&data);
+        assert_eq!((w, h), (bmp.width, bmp.height));
+        assert_eq!(pixels, bmp.pixels.concat());
+
+        let rgba = KittyOptions {
+            format: KittyFormat::Rgba,
+            columns: Some(12),
+            rows: Some(3),
+        };
+        let kitty = uint_kitty(&u, &LayoutOptions::default(), &style, &opts, &rgba);
+        let (keys, data) = decode_kitty(&kitty);
+        assert_eq!(key(&keys, "f"), Some("32"));
+        assert_eq!(key(&keys, "o"), Some("z"));
+        assert_eq!(key(&keys, "s"), Some(bmp.width.to_string().as_str()));
+        assert_eq!(key(&keys, "v"), Some(bmp.height.to_string().as_str()));
+        assert_eq!((key(&keys, "c"), key(&keys, "r")), (Some("12"), Some("3")));
+        let raw = miniz_oxide::inflate::decompress_to_vec_zlib(&data).unwrap();
+        assert_eq!(raw, bmp.pixels.concat());
+        // noise compresses poorly enough to need several chunks
+        let mut noisy = Bitmap::new(64, 64, [0, 0, 0, 255]);
+        for (i, p) in noisy.pixels.iter_mut().enumerate() {
+            *p = [
+                (i * 7919 % 251) as u8,
+                (i * 104729 % 241) as u8,
+                i as u8,
+                255,
+            ];
+        }
+        let kitty = encode_kitty(&noisy, &rgba);
+        assert!(kitty.matches("\x1b_G").count() > 1);
+        let raw = miniz_oxide::inflate::decompress_to_vec_zlib(&decode_kitty(&kitty).1).unwrap();
+        assert_eq!(raw, noisy.pixels.concat());
+        assert_eq!(
+            numeral_kitty(Numeral::new(3), &style, &opts, &png),
+            encode_kitty(&numeral_image(Numeral::new(3), &style, &opts), &png)
+        );
+    }
+
+    #[test]
+    fn test_size_control() {
+        let style = GlyphStyle::default();
+        for (max_width, max_height) in [(Some(100), None), (None, Some(40)), (Some(90), Some(90))] {
+            let opts = ImageOptions {
+                max_width,
+                max_height,
+                ..Default::default()
+            };
+            for n in [0, 31, 59] {
+                let bmp = numeral_image(Numeral::new(n), &style, &opts);
+                let (w, h) = (bmp.width, bmp.height);
+                assert!(w <= max_width.unwrap_or(w) && h <= max_height.unwrap_or(h));
+                // one side reaches its limit
+                assert!(
+                    Some(w) == max_width || Some(h) == max_height,
+                    "{} {:?}",
+                    n,
+                    (w, h)
+                );
+                let (dw, dh, _) = decode_sixel(&encode_sixel(&bmp));
+                assert_eq!((dw, dh), (w, h));
+            }
+        }
+        let bmp = numeral_image(Numeral::new(5), &style, &ImageOptions::default());
+        assert!(bmp.width > 16 && bmp.width < 64);
+    }
+
+    #[test]
+    fn test_size_too_small() {
+        // the default padding is 4 on each side
+        for max in [0, 8, 9] {
+            let opts = ImageOptions {
+                max_width: Some(max),
+                max_height: Some(max),
+                ..Default::default()
+            };
+            let image = numeral_image(Numeral::new(5), &GlyphStyle::default(), &opts);
+            assert_eq!((image.width, image.height), (9, 9));
+        }
+    }
+}
@@ -0,0 +1,150 @@
+#[cfg(test)]
+mod mesh_tests {
+    use hyxos_numerals::mesh::*;
+    use hyxos_numerals::unit_numeral::*;
+    use hyxos_numerals::*;
+    use std::f32::consts::PI;
+
+    fn unit_square() -> Mesh {
+        Mesh::new(
+            vec![
+                [0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [1.0, 1.0, 0.0],
+                [0.0, 1.0, 0.0],
+            ],
+            vec![0, 1, 2, 0, 2, 3],
+        )
+    }
+
+    fn signed_area(m: &Mesh) -> f32 {
+        m.triangles()
+            .map(|[a, b, c]| ((b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])) / 2.0)
+            .sum()
+    }
+
+    fn close(a: [f32; 4], b: [f32; 4]) -> bool {
+        a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-5)
+    }
+
+    #[test]
+    fn test_transforms() {
+        let sq = unit_square();
+        assert_eq!(sq.triangle_count(), 2);
+        assert!((sq.area() - 1.0).abs() < 1e-6);
+        assert!(close(
+            sq.translate(2.0, -1.0).bounds2d(),
+            [2.0, -1.0, 3.0, 0.0]
+        ));
+        assert!(close(sq.scale(2.0, 3.0).bounds2d(), [0.0, 0.0, 2.0, 3.0]));
+        assert!(close(sq.rotate(PI / 2.0).bounds2d(), [-1.0, 0.0, 0.0, 1.0]));
+        assert!(close(sq.skew(1.0, 0.0).bounds2d(), [0.0, 0.0, 2.0, 1.0]));
+        // rotation and skew preserve area and orientation
+        assert!((signed_area(&sq.rotate(0.7)) - 1.0).abs() < 1e-5);
+        assert!((signed_area(&sq.skew(0.5, 0.0)) - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_mirroring_keeps_winding() {
+        let sq = unit_square();
+        assert!(signed_area(&sq.scale(-1.0, 1.0)) > 0.0);
+        assert!(signed_area(&sq.scale(1.0, -1.0)) > 0.0);
+        assert!(signed_area(&sq.scale(-1.0, -1.0)) > 0.0);
+        assert!(signed_area(&sq.reverse_winding()) < 0.0);
+        // any map with a negative determinant mirrors
+        let flip = [[0.0, 1.0, 0.0], [1.0, 0.0, 0.0]];
+        assert!(signed_area(&sq.transform(flip)) > 0.0);
+        assert!(signed_area(&sq.skew(2.0, 1.0)) > 0.0);
+        assert!(signed_area(&sq.transform([[-2.0, 0.5, 1.0], [0.0, 1.0, 0.0]])) > 0.0);
+        assert_eq!(sq.transform(flip).vertices[1], [0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_merge_rebases_indices() {
+        let a = unit_square();
+        let b = unit_square().translate(5.0, 0.0);
+        let m = a.merge(&b);
+        assert_eq!(m.vertices.len(), 8);
+        assert_eq!(&m.indices[6..], &[4, 5, 6, 4, 6, 7]);
+        assert_eq!(m, Mesh::concat(&[a.clone(), b]));
+        assert!(close(m.bounds2d(), [0.0, 0.0, 6.0, 1.0]));
+        assert_eq!(m.to_2d()[5], [6.0, 0.0]);
+        assert_eq!(Mesh::default().merge(&a), a);
+    }
+
+    #[test]
+    fn test_numeral_mesh_matches_buffers() {
+        for u in Set::new().values() {
+            let m = numeral_mesh(u, PI / 3.0, 0.1, true, 16);
+            assert_eq!(m.vertices, numeral_vertices(u, PI / 3.0, 0.1, true, 16));
+            assert_eq!(m.indices, numeral_indices(u, 16));
+        }
+    }
+
+    #[test]
+    fn test_wind_ccw() {
+        for u in Set::new().values() {
+            let glyph = unit_glyph::glyph_mesh(u, PI / 3.0, 0.1, false).wind_ccw();
+            assert!(glyph
+                .triangles()
+                .all(|t| signed_area(&Mesh::new(t.to_vec(), vec![0, 1, 2])) > 0.0));
+        }
+        for d in 0..5 {
+            let m = unit_diacritic::diacritic_mesh(d, 16).unwrap();
+            let ccw = m.wind_ccw();
+            assert_eq!(ccw.vertices, m.vertices);
+            assert!((signed_area(&ccw) - m.area()).abs() < 1e-5);
+            assert_eq!(ccw.wind_ccw(), ccw);
+        }
+    }
+
+    #[test]
+    fn test_normals_and_uvs() {
+        let sq = unit_square().scale(2.0, 1.0);
+        assert!(sq.normals().iter().all(|n| *n == [0.0, 0.0, 1.0]));
+        assert!(sq
+            .reverse_winding()
+            .normals()
+            .iter()
+            .all(|n| *n == [0.0, 0.0, -1.0]));
+        assert_eq!(
+            sq.uvs(UvMapping::BoundingBox),
+            vec![[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]]
+        );
+        assert_eq!(sq.uvs(UvMapping::Planar(0.5))[2], [1.0, -0.5]);
+        let m = numeral_mesh(Numeral::new(17), PI / 3.0, 0.1, false, 16).wind_ccw();
+        assert_eq!(m.normals().len(), m.vertices.len());
+        assert!(m
+            .uvs(UvMapping::BoundingBox)
+            .iter()
+            .all(|uv| uv.iter().all(|c| (0.0..=1.0).contains(c))));
+    }
+
+    #[test]
+    fn test_validate() {
+        for u in Set::new().values() {
+            assert!(numeral_mesh(u, PI / 3.0, 0.1, false, 16).is_valid());
+        }
+        let mut m = unit_square();
+        m.indices.extend([0, 0, 1, 1, 2, 9, 0, 1]);
+        assert_eq!(
+            m.validate(),
+            vec![
+                MeshIssue::TrailingIndices(2),
+                MeshIssue::Degenerate { triangle: 2 },
+                MeshIssue::OutOfRange {
+                    triangle: 3,
+                    index: 9
+                },
+            ]
+        );
+        let collinear = Mesh::new(
+            vec![[0.0; 3], [1.0, 0.0, 0.0], [2.0, 0.0, 0.0]],
+            vec![0, 1, 2],
+        );
+        assert_eq!(
+            collinear.validate(),
+            vec![MeshIssue::Degenerate { triangle: 0 }]
+        );
+    }
+}
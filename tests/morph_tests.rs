@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod morph_tests {
+    use hyxos_numerals::morph::*;
+    use hyxos_numerals::raster::RasterOptions;
+    use hyxos_numerals::style::GlyphStyle;
+    use hyxos_numerals::svg::SvgStyle;
+    use hyxos_numerals::unit_numeral::numeral_mesh;
+    use hyxos_numerals::*;
+    use std::f32::consts::PI;
+
+    fn flat_area(m: &mesh::Mesh) -> f32 {
+        m.wind_ccw().area()
+    }
+
+    #[test]
+    fn test_easing() {
+        let all = [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+            Easing::Smoothstep,
+        ];
+        for e in all {
+            assert_eq!(e.apply(0.0), 0.0);
+            assert!((e.apply(1.0) - 1.0).abs() < 1e-6);
+            assert_eq!(e.apply(-1.0), 0.0);
+            let samples: Vec<f32> = (0..=20).map(|i| e.apply(i as f32 / 20.0)).collect();
+            assert!(samples.windows(2).all(|w| w[0] <= w[1]));
+        }
+        assert!(Easing::EaseIn.apply(0.5) < 0.5);
+        assert!(Easing::EaseOut.apply(0.5) > 0.5);
+        assert!((Easing::EaseInOut.apply(0.5) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_end_points_match_numerals() {
+        let set = Set::new().values();
+        for (a, b) in set.iter().zip(set.iter().skip(7)) {
+            let (a, b) = (*a, *b);
+            for (t, u) in [(0.0, a), (1.0, b)] {
+                let m = morph_mesh(a, b, t, &GlyphStyle::with(PI / 3.0, 0.1, false, 24));
+                let n = numeral_mesh(u, PI / 3.0, 0.1, false, 24);
+                assert!((flat_area(&m) - flat_area(&n)).abs() < 2e-3);
+                let (mb, nb) = (m.bounds2d(), n.bounds2d());
+                assert!(mb.iter().zip(nb).all(|(x, y)| (x - y).abs() < 1e-3));
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_diacritic_pair_keeps_its_vertices() {
+        // one numeral per diacritic
+        let firsts: Vec<Numeral> = (0..5).map(|i| Numeral::new(i * 12)).collect();
+        for sample_rate in [1, 2, 3, 24] {
+            let style = GlyphStyle::with(PI / 3.0, 0.1, false, sample_rate);
+            for a in &firsts {
+                for b in &firsts {
+                    let m = morph_mesh(*a, *b, 0.5, &style);
+                    let n = m.vertices.len() as u32;
+                    assert!(m.indices.iter().all(|i| *i < n));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_same_numeral_is_static() {
+        let u = Numeral::new(29);
+        let n = numeral_mesh(u, PI / 3.0, 0.1, true, 16);
+        for t in [0.0, 0.3, 1.0] {
+            let m = morph_mesh(u, u, t, &GlyphStyle::with(PI / 3.0, 0.1, true, 16));
+            assert_eq!(m.indices, n.indices);
+            assert!(m
+                .vertices
+                .iter()
+                .zip(&n.vertices)
+                .all(|(p, q)| (0..3).all(|k| (p[k] - q[k]).abs() < 1e-6)));
+        }
+    }
+
+    #[test]
+    fn test_frames() {
+        let (a, b) = (Numeral::new(3), Numeral::new(50));
+        let opts = MorphOptions {
+            frames: 6,
+            easing: Easing::Smoothstep,
+        };
+        let frames = morph_frames(a, b, &opts, &GlyphStyle::with(PI / 3.0, 0.1, false, 16));
+        assert_eq!(frames.len(), 6);
+        // sections still to grow in are collapsed to points, never broken
+        assert!(frames[0]
+            .validate()
+            .iter()
+            .all(|i| matches!(i, mesh::MeshIssue::Degenerate { .. })));
+        assert!(frames[2].is_valid());
+
+        let svgs = morph_svg_frames(a, b, &opts, &SvgStyle::default());
+        assert_eq!(svgs.len(), 6);
+        let view_box = |s: &str| s.split("viewBox=").nth(1).unwrap()[..40].to_string();
+        assert!(svgs.iter().all(|s| view_box(s) == view_box(&svgs[0])));
+
+        let bitmaps = rasterize_frames(&frames, &RasterOptions::default());
+        assert!(bitmaps
+            .iter()
+            .all(|b| (b.width, b.height) == (bitmaps[0].width, bitmaps[0].height)));
+        let pngs = png_frames(&frames, &RasterOptions::default());
+        assert!(pngs.iter().all(|p| p.starts_with(b"\x89PNG")));
+    }
+
+    #[test]
+    #[should_panic(expected = "Need at least two frames!")]
+    fn test_single_frame_panics() {
+        let opts = MorphOptions {
+            frames: 1,
+            ..MorphOptions::default()
+        };
+        morph_frames(
+            Numeral::new(0),
+            Numeral::new(1),
+            &opts,
+            &GlyphStyle::with(PI / 3.0, 0.1, false, 16),
+        );
+    }
+}
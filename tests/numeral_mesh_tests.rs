@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod numeral_mesh_tests {
+    use hyxos_numerals::mesh::Mesh;
+    use hyxos_numerals::unit_glyph::*;
+    use hyxos_numerals::unit_numeral::*;
+    use hyxos_numerals::*;
+    use std::f32::consts::PI;
+
+    fn y_range(v: &[[f32; 3]]) -> (f32, f32) {
+        v.iter().fold((f32::MAX, f32::MIN), |(lo, hi), p| {
+            (lo.min(p[1]), hi.max(p[1]))
+        })
+    }
+
+    #[test]
+    fn test_indices_in_range() {
+        for u in Set::new().values() {
+            let Mesh {
+                vertices: v,
+                indices: idx,
+            } = numeral_mesh(u, PI / 3.0, 0.1, false, 16);
+            assert_eq!(idx.len() % 3, 0);
+            assert!(idx.iter().all(|&i| (i as usize) < v.len()));
+            assert!(idx.iter().any(|&i| i as usize >= GLYPH_VERTEX_COUNT));
+            assert_eq!(
+                &idx[..double_section_select(u).len()],
+                &double_section_select(u)[..]
+            );
+        }
+    }
+
+    #[test]
+    fn test_glyph_is_centered() {
+        for flip in [false, true] {
+            let v = numeral_vertices(Numeral::new(5), PI / 3.0, 0.1, flip, 16);
+            let (lo, hi) = y_range(&v[..GLYPH_VERTEX_COUNT]);
+            assert!((lo + hi).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_diacritic_caps_apex() {
+        for u in Set::new().values() {
+            let v = numeral_vertices(u, PI / 3.0, 0.1, false, 16);
+            let (_, glyph_top) = y_range(&v[..GLYPH_VERTEX_COUNT]);
+            let (dia_bottom, _) = y_range(&v[GLYPH_VERTEX_COUNT..]);
+            assert!((dia_bottom - glyph_top - DIACRITIC_GAP).abs() < 1e-5);
+
+            let v = numeral_vertices(u, PI / 3.0, 0.1, true, 16);
+            let (glyph_bottom, _) = y_range(&v[..GLYPH_VERTEX_COUNT]);
+            let (_, dia_top) = y_range(&v[GLYPH_VERTEX_COUNT..]);
+            assert!((glyph_bottom - dia_top - DIACRITIC_GAP).abs() < 1e-5);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod ordering_tests {
+    use hyxos_numerals::*;
+
+    #[test]
+    fn test_nat_sort_matches_natural_scheme() {
+        let set = Set::new().shuffle();
+        let a: Vec<u8> = set.nat_sort().values().iter().map(|n| n.u()).collect();
+        let b: Vec<u8> = set
+            .sort_by_scheme(SortScheme::Natural)
+            .values()
+            .iter()
+            .map(|n| n.u())
+            .collect();
+        assert_eq!(a, b);
+        assert_eq!(set.nat_sort().values()[0].natural_order_index(), 0);
+    }
+
+    #[test]
+    fn test_schemes_are_total_and_deterministic() {
+        for o in SortScheme::ALL {
+            let a = Set::new().shuffle().sort_by_scheme(o).values();
+            let b = Set::new().shuffle().sort_by_scheme(o).values();
+            assert_eq!(a, b);
+            assert_eq!(a.len(), 60);
+        }
+        let v = Set::new()
+            .shuffle()
+            .sort_by_scheme(SortScheme::Value)
+            .values();
+        assert_eq!(v[0].u(), 0);
+        assert_eq!(v[59].u(), 59);
+    }
+
+    #[test]
+    fn test_scheme_keys() {
+        let western = Set::new().sort_by_scheme(SortScheme::Western).values();
+        assert_eq!(western[0].western_sign(), "aries");
+        assert_eq!(western[59].western_sign(), "pisces");
+
+        let stems = Set::new().sort_by_scheme(SortScheme::HeavenlyStem).values();
+        assert_eq!(stems[0].heavenly_stem(), "甲");
+        assert_eq!(stems[59].heavenly_stem(), "癸");
+
+        let spoken = Set::new().sort_by_scheme(SortScheme::Spoken).values();
+        assert_eq!(spoken[0].spoken_name(), "awk");
+        assert!(spoken
+            .windows(2)
+            .all(|w| w[0].spoken_name() <= w[1].spoken_name()));
+
+        let shape = Set::new().sort_by_scheme(SortScheme::Shape).values();
+        assert!(shape
+            .windows(2)
+            .all(|w| (w[0].row_index(), w[0].col_index()) <= (w[1].row_index(), w[1].col_index())));
+    }
+
+    #[test]
+    fn test_cycle_from_wraps() {
+        let set = Set::new();
+        let c: Vec<u8> = set.cycle_from(Numeral::new(58)).map(|n| n.u()).collect();
+        assert_eq!(c.len(), 60);
+        assert_eq!(&c[..3], &[58, 59, 0]);
+        assert_eq!(c[59], 57);
+
+        let nat = set.nat_sort();
+        let start = Numeral::new(21);
+        let c: Vec<Numeral> = nat.cycle_from(start).collect();
+        assert_eq!(c[0], start);
+        assert_eq!(c[1].natural_order_index(), start.natural_order_index() + 1);
+    }
+
+    #[test]
+    fn test_cycle_from_missing_start() {
+        let evens = Set::new().filter(|n| n.u() % 2 == 0);
+        assert_eq!(evens.values().len(), 30);
+        assert_eq!(evens.cycle_from(Numeral::new(3)).count(), 0);
+        let c: Vec<u8> = evens.cycle_from(Numeral::new(56)).map(|n| n.u()).collect();
+        assert_eq!(&c[..3], &[56, 58, 0]);
+        assert_eq!(c.len(), 30);
+    }
+
+    #[test]
+    fn test_no_clash_with_std_ordering() {
+        use std::cmp::Ordering;
+        let mut v = Set::new().values();
+        v.sort_by(|a, b| match SortScheme::Western.compare(a, b) {
+            Ordering::Equal => Ordering::Equal,
+            o => o.reverse(),
+        });
+        assert_eq!(
+            v[0],
+            Set::new().sort_by_scheme(SortScheme::Western).values()[59]
+        );
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod outline_tests {
+    use hyxos_numerals::mesh::Mesh;
+    use hyxos_numerals::outline::*;
+    use hyxos_numerals::style::GlyphStyle;
+    use hyxos_numerals::unit_numeral::numeral_mesh;
+    use hyxos_numerals::*;
+    use std::f32::consts::PI;
+
+    #[test]
+    fn test_square_with_hole() {
+        // 3x3 grid of unit squares with the middle one missing
+        let mut v: Vec<[f32; 3]> = Vec::new();
+        for y in 0..4 {
+            for x in 0..4 {
+                v.push([x as f32, y as f32, 0.0]);
+            }
+        }
+        let mut idx: Vec<u32> = Vec::new();
+        for y in 0..3u32 {
+            for x in 0..3u32 {
+                if (x, y) == (1, 1) {
+                    continue;
+                }
+                let i = y * 4 + x;
+                idx.extend([i, i + 1, i + 5, i, i + 5, i + 4]);
+            }
+        }
+        let path = outline(&Mesh::new(v, idx));
+        assert_eq!(path.contours.len(), 2);
+        // collinear grid points are simplified away
+        assert!(path.contours.iter().all(|c| c.points.len() == 4));
+        assert!((path.area() - 8.0).abs() < 1e-5);
+        let regions = path.regions();
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions[0].holes.len(), 1);
+        assert!((regions[0].holes[0].signed_area() + 1.0).abs() < 1e-5);
+        assert!(path.contains([0.5, 0.5]));
+        assert!(!path.contains([1.5, 1.5]));
+        assert!(path.to_svg_d().starts_with('M'));
+    }
+
+    #[test]
+    fn test_numeral_outlines_match_mesh_area() {
+        for u in Set::new().values() {
+            let mesh = numeral_mesh(u, PI / 3.0, 0.1, false, 24);
+            let path = numeral_outline(u, &GlyphStyle::with(PI / 3.0, 0.1, false, 24));
+            assert!((path.area() - mesh.area()).abs() < 1e-3);
+            assert!(path.contours.iter().all(|c| c.points.len() >= 3));
+            // the glyph body and the diacritic are separate pieces
+            assert!(path.regions().len() >= 2);
+        }
+        let tazo = numeral_outline(Numeral::new(0), &GlyphStyle::with(PI / 3.0, 0.1, false, 24));
+        assert_eq!(tazo.contours.iter().filter(|c| c.is_hole()).count(), 2);
+    }
+
+    #[test]
+    fn test_part_outlines() {
+        for d in Diacritic::ALL {
+            let path = diacritic_outline(d, &GlyphStyle::default());
+            assert_eq!(path.contours.len(), 1);
+            assert!(!path.contours[0].is_hole());
+        }
+        let body = glyph_outline(Numeral::new(0), &GlyphStyle::default());
+        assert_eq!(body.regions().len(), 1);
+        assert_eq!(body.regions()[0].holes.len(), 2);
+    }
+}
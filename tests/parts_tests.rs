@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod parts_tests {
+    use hyxos_numerals::unit_glyph::InvalidGlyph;
+    use hyxos_numerals::*;
+
+    #[test]
+    fn test_round_trip() {
+        for u in Set::new().values() {
+            let (d, g) = u.parts();
+            assert_eq!(Numeral::from_parts(d, g), u);
+            assert_eq!(d.index(), u.diacritic_index());
+            assert_eq!(g.index(), u.duodecimal_index());
+            assert_eq!(format!("{}{}", d.char(), g.char()), u.encoding());
+            assert_eq!(d.name(), u.diacritic_name());
+            assert_eq!(g.name(), u.duodecimal_name());
+        }
+        assert_eq!(
+            Numeral::from_parts(Diacritic::Wu, Glyph::Lev),
+            Numeral::new(59)
+        );
+    }
+
+    #[test]
+    fn test_names_and_chars() {
+        let names: Vec<&str> = Glyph::ALL.iter().map(|g| g.name()).collect();
+        assert_eq!(names[0], "zo");
+        assert_eq!(names[11], "lev");
+        assert_eq!(Diacritic::Shey.name(), "shey");
+        for d in Diacritic::ALL {
+            assert_eq!(Diacritic::from_char(d.char()), Some(d));
+        }
+        for g in Glyph::ALL {
+            assert_eq!(Glyph::from_char(g.char()), Some(g));
+            assert_eq!(Glyph::try_from(g.index()), Ok(g));
+        }
+        assert_eq!(Diacritic::from_char('x'), None);
+        assert_eq!(Glyph::from_char('c'), None);
+        assert_eq!(Glyph::try_from(12), Err(InvalidGlyph(12)));
+    }
+
+    #[test]
+    fn test_iteration() {
+        let ree: Vec<Numeral> = Diacritic::Ree.numerals().collect();
+        assert_eq!(ree, (24..36).map(Numeral::new).collect::<Vec<_>>());
+        let hyx: Vec<u8> = Glyph::Hyx.numerals().map(|n| n.u()).collect();
+        assert_eq!(hyx, vec![6, 18, 30, 42, 54]);
+        let all: Vec<Numeral> = Diacritic::ALL.iter().flat_map(|d| d.numerals()).collect();
+        assert_eq!(all, Set::new().values());
+    }
+
+    #[test]
+    fn test_match_on_parts() {
+        let tier = |u: Numeral| match u.parts() {
+            (Diacritic::Ta, _) => "plain",
+            (_, Glyph::Zo) => "bare",
+            _ => "compound",
+        };
+        assert_eq!(tier(Numeral::new(5)), "plain");
+        assert_eq!(tier(Numeral::new(12)), "bare");
+        assert_eq!(tier(Numeral::new(13)), "compound");
+        assert_eq!(Numeral::new(30).spoken_name(), "reex");
+        assert_eq!(Numeral::new_from_enc("w8"), Numeral::new(56));
+    }
+}
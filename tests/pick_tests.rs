@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod pick_tests {
+    use hyxos_numerals::pick::*;
+    use hyxos_numerals::style::GlyphStyle;
+    use hyxos_numerals::typeset::*;
+    use hyxos_numerals::unit_glyph::section_keys;
+    use hyxos_numerals::*;
+
+    fn area(t: &[[f32; 3]; 3]) -> f32 {
+        let [a, b, c] = t;
+        ((b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])).abs() / 2.0
+    }
+
+    fn centroid(t: [[f32; 3]; 3]) -> [f32; 2] {
+        [0, 1].map(|k| (t[0][k] + t[1][k] + t[2][k]) / 3.0)
+    }
+
+    #[test]
+    fn test_numeral_hits() {
+        for flip in [false, true] {
+            let style = GlyphStyle {
+                flip,
+                ..GlyphStyle::italic()
+            };
+            for u in Set::new().values() {
+                let parts = numeral_parts(u, &style);
+                let keys: Vec<usize> = parts
+                    .iter()
+                    .filter_map(|(p, _)| match p {
+                        Part::Section(k) => Some(*k),
+                        Part::Diacritic => None,
+                    })
+                    .collect();
+                assert_eq!(keys, section_keys(u));
+                for (part, m) in &parts {
+                    // ta's fan closes with a sliver too thin to land in
+                    for t in m.triangles().filter(|t| area(t) > 1e-6) {
+                        let hit = hit_test(u, &style, centroid(t)).unwrap();
+                        assert_eq!((hit.numeral, hit.part), (u, *part));
+                    }
+                }
+                let [x0, y0, x1, y1] = numeral_bounds(u, &style);
+                assert_eq!(hit_test(u, &style, [x1 + 0.01, y0]), None);
+                for p in style.numeral_mesh(u).triangles().flatten() {
+                    assert!(p[0] >= x0 && p[0] <= x1 && p[1] >= y0 && p[1] <= y1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_tight_bounds() {
+        let style = GlyphStyle::default();
+        // 1 is only the stem, so the unused leg vertices of its layout span
+        // fall outside
+        let u = Numeral::new(1);
+        let loose = style.numeral_mesh(u).bounds2d();
+        let tight = numeral_bounds(u, &style);
+        assert!(tight[0] > loose[0] && tight[2] < loose[2]);
+        assert_eq!([tight[1], tight[3]], [loose[1], loose[3]]);
+        assert_eq!(tight_bounds(&mesh::Mesh::default()), None);
+    }
+
+    #[test]
+    fn test_picker() {
+        let u = Uint::new(60usize.pow(2) * 12 + 60 * 25 + 7);
+        let opts = LayoutOptions {
+            separator: Some(Separator::Dot),
+            ..LayoutOptions::default()
+        };
+        let style = GlyphStyle::default();
+        let picker = Picker::new(&u, &opts, &style);
+        let placements = picker.placements();
+        let bounds = picker.bounds();
+        assert_eq!(placements.len(), 3);
+        for (p, b) in placements.iter().zip(&bounds) {
+            let (part, m) = &numeral_parts(p.numeral, &style)[0];
+            let t = m.triangles().next().unwrap();
+            let c = centroid(t);
+            let c = [c[0] + p.origin[0], c[1] + p.origin[1]];
+            let hit = picker.hit(c).unwrap();
+            assert_eq!(
+                (hit.index, hit.numeral, hit.part),
+                (p.index, p.numeral, *part)
+            );
+            assert_eq!(picker.nearest(c).unwrap().1, 0.0);
+            assert!(c[0] > b[0] && c[0] < b[2]);
+        }
+        assert!(bounds.windows(2).all(|w| w[0][2] < w[1][0]));
+        // empty space between digits belongs to no one, but is near the
+        // closer neighbour
+        let gap = [(bounds[0][2] + bounds[1][0]) / 2.0 - 0.01, 0.0];
+        assert_eq!(picker.hit(gap), None);
+        let right = [bounds[2][2] + 0.5, 0.5];
+        let (hit, d) = picker.nearest(right).unwrap();
+        assert_eq!(hit.index, 2);
+        assert!((0.5..1.0).contains(&d));
+        let empty = Picker::new(&Uint::new(0), &opts, &style);
+        assert_eq!(empty.placements().len(), 1);
+    }
+}
@@ -0,0 +1,106 @@
+mod common;
+
+#[cfg(test)]
+mod raster_tests {
+    use crate::common::decode_png;
+    use hyxos_numerals::raster::*;
+    use hyxos_numerals::style::GlyphStyle;
+    use hyxos_numerals::typeset::LayoutOptions;
+    use hyxos_numerals::*;
+    use std::f32::consts::PI;
+
+    #[test]
+    fn test_png_round_trip() {
+        let opts = RasterOptions {
+            background: [0, 0, 0, 0],
+            ..Default::default()
+        };
+        let bmp = rasterize_numeral(
+            Numeral::new(44),
+            &GlyphStyle::with(PI / 3.0, 0.1, false, 16),
+            &opts,
+        );
+        let (w, h, pixels) = decode_png(&bmp.to_png());
+        assert_eq!((w, h), (bmp.width, bmp.height));
+        assert_eq!(pixels, bmp.pixels.concat());
+        assert_eq!(bmp.get(0, 0), [0, 0, 0, 0]);
+        assert!(bmp.pixels.iter().any(|p| p[3] == 255));
+        // anti-aliased edges
+        assert!(bmp.pixels.iter().any(|p| p[3] > 0 && p[3] < 255));
+    }
+
+    #[test]
+    fn test_empty_mesh() {
+        let opts = RasterOptions {
+            padding: 0,
+            background: [0, 0, 0, 0],
+            ..Default::default()
+        };
+        let bmp = rasterize(&mesh::Mesh::default(), &opts);
+        assert_eq!((bmp.width, bmp.height), (1, 1));
+        let (w, h, pixels) = decode_png(&bmp.to_png());
+        assert_eq!((w, h, pixels), (1, 1, vec![0; 4]));
+    }
+
+    #[test]
+    #[should_panic(expected = "A PNG needs at least one row and one column!")]
+    fn test_empty_png() {
+        Bitmap::new(0, 3, [0; 4]).to_png();
+    }
+
+    #[test]
+    fn test_netpbm() {
+        let opts = RasterOptions {
+            samples: 1,
+            ..Default::default()
+        };
+        let bmp = rasterize_numeral(
+            Numeral::new(0),
+            &GlyphStyle::with(PI / 3.0, 0.1, false, 16),
+            &opts,
+        );
+        let header = format!("P5\n{} {}\n255\n", bmp.width, bmp.height);
+        let pgm = bmp.to_pgm();
+        assert!(pgm.starts_with(header.as_bytes()));
+        assert_eq!(pgm.len(), header.len() + bmp.width * bmp.height);
+        // without anti-aliasing every pixel is either ink or paper
+        assert!(pgm[header.len()..].iter().all(|&p| p == 0 || p == 255));
+        let ppm = bmp.to_ppm();
+        assert!(ppm.starts_with(b"P6\n"));
+        assert!(ppm.ends_with(&[255, 255, 255]));
+    }
+
+    #[test]
+    fn test_size_and_padding() {
+        let small = RasterOptions {
+            scale: 20.0,
+            padding: 0,
+            ..Default::default()
+        };
+        let large = RasterOptions {
+            scale: 80.0,
+            padding: 10,
+            ..Default::default()
+        };
+        let a = rasterize_numeral(
+            Numeral::new(7),
+            &GlyphStyle::with(PI / 3.0, 0.1, false, 16),
+            &small,
+        );
+        let b = rasterize_numeral(
+            Numeral::new(7),
+            &GlyphStyle::with(PI / 3.0, 0.1, false, 16),
+            &large,
+        );
+        assert!((b.width as i64 - 20 - 4 * a.width as i64).abs() <= 4);
+        assert!((0..b.width).all(|x| b.get(x, 0) == [255, 255, 255, 255]));
+
+        let line = rasterize_uint(
+            &Uint::new(3661),
+            &LayoutOptions::default(),
+            &GlyphStyle::with(PI / 3.0, 0.1, false, 16),
+            &small,
+        );
+        assert!(line.width > 2 * a.width);
+    }
+}
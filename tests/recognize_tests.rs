@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod recognize_tests {
+    use hyxos_numerals::raster::*;
+    use hyxos_numerals::recognize::*;
+    use hyxos_numerals::style::GlyphStyle;
+    use hyxos_numerals::unit_glyph::{double_section_indices, double_section_keys};
+    use hyxos_numerals::*;
+
+    // What a pen would trace: each section along its long axis and the
+    // diacritic along its middle, or round its rim for ta
+    fn pen_strokes(u: Numeral, style: &GlyphStyle) -> Vec<Vec<[f32; 2]>> {
+        let v = style.glyph_mesh(u).vertices;
+        let mut strokes: Vec<Vec<[f32; 2]>> = double_section_keys(u)
+            .into_iter()
+            .map(|k| {
+                let mut q: Vec<[f32; 2]> = double_section_indices(k)
+                    .iter()
+                    .map(|&i| [v[i as usize][0], v[i as usize][1]])
+                    .collect();
+                q.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                q.dedup();
+                let n = q.len() as f32;
+                let c = [0, 1].map(|k| q.iter().map(|p| p[k]).sum::<f32>() / n);
+                let (mut dir, mut best) = ([1.0, 0.0], 0.0);
+                for a in &q {
+                    for b in &q {
+                        let d = (a[0] - b[0]).hypot(a[1] - b[1]);
+                        if d > best {
+                            (dir, best) = ([(b[0] - a[0]) / d, (b[1] - a[1]) / d], d);
+                        }
+                    }
+                }
+                let proj = q
+                    .iter()
+                    .map(|p| (p[0] - c[0]) * dir[0] + (p[1] - c[1]) * dir[1]);
+                let lo = proj.clone().fold(f32::MAX, f32::min);
+                let hi = proj.fold(f32::MIN, f32::max);
+                [lo, hi]
+                    .map(|s| [c[0] + dir[0] * s, c[1] + dir[1] * s])
+                    .to_vec()
+            })
+            .collect();
+        let d = u.diacritic();
+        let m = style.diacritic_transform(d);
+        let curve = style.diacritic_curve(d);
+        let place = |p: [f32; 2]| {
+            [
+                m[0][0] * p[0] + m[0][1] * p[1] + m[0][2],
+                m[1][0] * p[0] + m[1][1] * p[1] + m[1][2],
+            ]
+        };
+        strokes.push(
+            (0..=20)
+                .map(|i| {
+                    let t = i as f32 / 20.0;
+                    if d == Diacritic::Ta {
+                        return place(curve.point(t));
+                    }
+                    let (a, b) = (curve.upper(t), curve.lower(t));
+                    place([(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0])
+                })
+                .collect(),
+        );
+        strokes
+    }
+
+    #[test]
+    fn test_bitmaps() {
+        for flip in [false, true] {
+            let style = GlyphStyle {
+                flip,
+                ..GlyphStyle::default()
+            };
+            let r = Recognizer::new(&style, RecognizerOptions::default());
+            for u in Set::new().values() {
+                for scale in [16.0, 48.0] {
+                    let opts = RasterOptions {
+                        scale,
+                        ..Default::default()
+                    };
+                    let c = r.recognize_bitmap(&rasterize_numeral(u, &style, &opts));
+                    assert_eq!(c[0].numeral, u);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_strokes() {
+        // a wobbly hand on a tablet, in tablet units
+        let mut seed: u32 = 7;
+        let mut wobble = || {
+            seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+            6.0 * ((seed >> 8) as f32 / (1u32 << 24) as f32 - 0.5)
+        };
+        for style in [GlyphStyle::regular(), GlyphStyle::italic()] {
+            let r = Recognizer::new(&style, RecognizerOptions::default());
+            for u in Set::new().values() {
+                let strokes: Vec<Vec<[f32; 2]>> = pen_strokes(u, &style)
+                    .iter()
+                    .map(|s| {
+                        s.iter()
+                            .map(|p| [300.0 * p[0] + 100.0 + wobble(), 300.0 * p[1] + wobble()])
+                            .collect()
+                    })
+                    .collect();
+                let c = r.recognize_strokes(&strokes);
+                assert_eq!(c.len(), 60);
+                assert_eq!(c[0].numeral, u);
+                assert!(c.windows(2).all(|w| w[0].confidence >= w[1].confidence));
+                assert!(c.iter().all(|c| (0.0..=1.0).contains(&c.confidence)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let r = Recognizer::new(&GlyphStyle::default(), RecognizerOptions::default());
+        assert!(r.recognize_strokes(&[]).is_empty());
+        assert!(r.recognize_strokes(&[vec![]]).is_empty());
+        assert!(r
+            .recognize_bitmap(&Bitmap::new(8, 8, [255, 255, 255, 255]))
+            .is_empty());
+        // a lone dot is still ink
+        assert_eq!(r.recognize_strokes(&[vec![[1.0, 1.0]]]).len(), 60);
+    }
+}
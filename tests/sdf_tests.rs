@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod sdf_tests {
+    use hyxos_numerals::outline::{Contour, Path};
+    use hyxos_numerals::sdf::*;
+    use hyxos_numerals::style::GlyphStyle;
+    use hyxos_numerals::*;
+    use std::f32::consts::PI;
+
+    fn square() -> Path {
+        Path {
+            contours: vec![Contour {
+                points: vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_square_field() {
+        let opts = SdfOptions {
+            scale: 10.0,
+            spread: 4.0,
+        };
+        let f = path_sdf(&square(), &opts);
+        assert_eq!((f.width, f.height), (18, 18));
+        // pixel centers of the middle row, from the padding into the square
+        let row = &f.values[9 * f.width..10 * f.width];
+        assert!((row[0] + 3.5).abs() < 1e-4);
+        assert!((row[4] - 0.5).abs() < 1e-4);
+        assert!((row[8] - 4.5).abs() < 1e-4);
+        let bytes = f.to_bytes();
+        assert_eq!(bytes[9 * f.width], 16);
+        assert_eq!(bytes[9 * f.width + 8], 255);
+        assert!((bytes[9 * f.width + 4] as i32 - 143).abs() <= 1);
+        assert!(f.to_pgm().starts_with(b"P5\n18 18\n255\n"));
+        assert!(f.to_png().starts_with(b"\x89PNG"));
+    }
+
+    #[test]
+    fn test_numeral_field_sign() {
+        let f = numeral_sdf(
+            Numeral::new(0),
+            &GlyphStyle::with(PI / 3.0, 0.1, false, 24),
+            &SdfOptions::default(),
+        );
+        assert!(f.values.iter().any(|d| *d > 0.0));
+        assert!(f.values[0] < 0.0);
+        assert!(f.values.iter().all(|d| d.is_finite()));
+        // the glyph is thinner than the spread, so nothing inside saturates
+        assert!(f.values.iter().all(|d| *d < 4.0));
+    }
+
+    #[test]
+    fn test_atlas() {
+        let opts = SdfOptions {
+            scale: 8.0,
+            spread: 2.0,
+        };
+        let style = GlyphStyle::with(PI / 3.0, 0.1, false, 12);
+        let atlas = sdf_atlas(&style, &opts, 12);
+        assert_eq!(atlas.pixels.len(), atlas.width * atlas.height);
+        assert_eq!(
+            (atlas.width, atlas.height),
+            (12 * atlas.cell[0], 5 * atlas.cell[1])
+        );
+        assert_eq!(atlas.glyphs.len(), 60);
+        for (i, g) in atlas.glyphs.iter().enumerate() {
+            assert_eq!(g.numeral, Numeral::new(i as u8));
+            let f = numeral_sdf(g.numeral, &style, &opts);
+            let [x, y, w, h] = g.rect;
+            assert_eq!([x, y], [(i % 12) * atlas.cell[0], (i / 12) * atlas.cell[1]]);
+            assert_eq!((w, h), (f.width, f.height));
+            // the field sits in the rect byte for byte
+            let bytes = f.to_bytes();
+            for row in 0..h {
+                let start = (y + row) * atlas.width + x;
+                assert_eq!(
+                    &atlas.pixels[start..start + w],
+                    &bytes[row * w..(row + 1) * w]
+                );
+            }
+            // the bearing is the pen's offset to the field's corner
+            let [lx0, _] = typeset::digit_span(&style);
+            let baseline = -0.5 * style.height();
+            assert!((g.bearing[0] - (f.origin[0] - lx0) * opts.scale).abs() < 1e-3);
+            assert!((g.bearing[1] - (f.origin[1] - baseline) * opts.scale).abs() < 1e-3);
+        }
+        assert_eq!(atlas.get("t0"), Some(&atlas.glyphs[0]));
+        assert!(atlas.get("wb").is_some());
+        assert!(atlas.to_pgm().len() > atlas.pixels.len());
+    }
+
+    #[test]
+    fn test_atlas_json() {
+        let atlas = sdf_atlas(&GlyphStyle::default(), &SdfOptions::default(), 12);
+        let json = atlas.to_json();
+        #[cfg(feature = "serde")]
+        assert_eq!(serde_json::to_string(&atlas).unwrap(), json);
+        let v: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(v["width"], atlas.width);
+        assert_eq!(v["spread"], 4.0);
+        assert!(v.get("pixels").is_none());
+        assert_eq!(v["glyphs"].as_object().unwrap().len(), 60);
+        assert_eq!(v["glyphs"]["t0"]["rect"][0], 0);
+        assert_eq!(v["glyphs"]["wb"]["value"], 59);
+    }
+
+    #[test]
+    #[should_panic(expected = "An atlas needs at least one column!")]
+    fn test_atlas_no_columns() {
+        sdf_atlas(&GlyphStyle::default(), &SdfOptions::default(), 0);
+    }
+}
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod solar_tests {
+    use hyxos_numerals::solar::*;
+
+    #[test]
+    fn test_julian_day() {
+        assert_eq!(julian_day(2000, 1, 1, 12.0), 2451545.0);
+        assert_eq!(julian_day(1957, 10, 4, 19.26), 2436116.3025);
+        assert_eq!(julian_day(1988, 2, 29, 0.0), 2447220.5);
+    }
+
+    #[test]
+    fn test_solar_longitude() {
+        // Meeus example 25.a, 1992 October 13.0
+        let l = solar_longitude(2448908.5);
+        assert!((l - 199.90895).abs() < 0.001);
+        // March equinox 2000, 07:35 UT
+        let l = solar_longitude(julian_day(2000, 3, 20, 7.0 + 35.0 / 60.0));
+        assert!(!(0.01..=359.99).contains(&l));
+    }
+
+    #[test]
+    fn test_sun_sign_cusps() {
+        // March equinox 2024 fell at 03:06 UT
+        let before = SunSign::new(2024, 3, 20, 2.0);
+        let after = SunSign::new(2024, 3, 20, 4.0);
+        assert_eq!(before.sign(), "pisces");
+        assert_eq!(before.duodecimal_index(), 0);
+        assert!(before.degree() > 29.9);
+        assert_eq!(after.sign(), "aries");
+        assert_eq!(after.sign_index(), 0);
+        assert!(after.degree() < 0.1);
+
+        let leo = SunSign::new(1990, 8, 10, 12.0);
+        assert_eq!(leo.sign(), "leo");
+        let numerals = leo.numerals();
+        assert_eq!(numerals.len(), 5);
+        assert!(numerals.iter().all(|n| n.western_sign() == "leo"));
+        assert!(leo.degree() >= 0.0 && leo.degree() < 30.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_date() {
+        SunSign::new(2023, 2, 29, 0.0);
+    }
+}
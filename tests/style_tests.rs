@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod style_tests {
+    use hyxos_numerals::font::*;
+    use hyxos_numerals::raster::*;
+    use hyxos_numerals::style::*;
+    use hyxos_numerals::svg::*;
+    use hyxos_numerals::unit_numeral::numeral_mesh;
+    use hyxos_numerals::*;
+    use std::f32::consts::PI;
+
+    fn width(s: &GlyphStyle, u: Numeral) -> f32 {
+        let [x0, _, x1, _] = s.numeral_mesh(u).bounds2d();
+        x1 - x0
+    }
+
+    #[test]
+    fn test_regular_matches_loose_parameters() {
+        for u in Set::new().values() {
+            for flip in [false, true] {
+                let s = GlyphStyle::with(PI / 3.0, 0.1, flip, 16);
+                assert_eq!(s.numeral_mesh(u), numeral_mesh(u, PI / 3.0, 0.1, flip, 16));
+            }
+        }
+        assert_eq!(GlyphStyle::default(), GlyphStyle::regular());
+    }
+
+    #[test]
+    fn test_presets() {
+        for name in PRESETS {
+            assert!(GlyphStyle::preset(name).is_some());
+        }
+        assert_eq!(GlyphStyle::preset("fraktur"), None);
+        let u = Numeral::new(27);
+        let regular = GlyphStyle::regular();
+        let bold = GlyphStyle::bold();
+        assert!(bold.numeral_mesh(u).wind_ccw().area() > regular.numeral_mesh(u).wind_ccw().area());
+        assert!(width(&GlyphStyle::condensed(), u) < width(&regular, u));
+        // the apex leans right
+        let italic = GlyphStyle::italic().glyph_mesh(u);
+        let [_, _, _, top] = italic.bounds2d();
+        let apex = italic.vertices.iter().find(|p| p[1] == top).unwrap();
+        assert!(apex[0] > 0.0);
+    }
+
+    #[test]
+    fn test_proportions() {
+        let u = Numeral::new(0);
+        let regular = GlyphStyle::regular();
+        let wide = GlyphStyle {
+            diacritic_radius: 2.0 * regular.diacritic_radius,
+            diacritic_scale: 0.5,
+            ..regular
+        };
+        let d = |s: &GlyphStyle| s.numeral_mesh(u).bounds2d()[3];
+        assert!(d(&wide) > d(&regular));
+        let gap = GlyphStyle {
+            diacritic_gap: 0.3,
+            ..regular
+        };
+        assert!((d(&gap) - d(&regular) - 0.2).abs() < 1e-5);
+        let raised = GlyphStyle {
+            stem_offset: 4.0,
+            ..regular
+        };
+        let a = regular.glyph_mesh(Numeral::new(1));
+        let b = raised.glyph_mesh(Numeral::new(1));
+        assert_eq!(a.vertices[0], b.vertices[0]);
+        assert!((b.vertices[12][1] - a.vertices[12][1] - regular.weight).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_renderers_accept_styles() {
+        let u = Numeral::new(41);
+        for name in PRESETS {
+            let glyph = GlyphStyle::preset(name).unwrap();
+            let svg = numeral_svg(
+                u,
+                &SvgStyle {
+                    glyph,
+                    ..Default::default()
+                },
+            );
+            assert!(svg.starts_with("<svg"));
+            let bmp = rasterize_numeral(u, &glyph, &RasterOptions::default());
+            assert!(bmp.width > 0);
+            let font = build_font(&FontOptions {
+                glyph,
+                ..Default::default()
+            });
+            assert!(ttf_parser::Face::parse(&font, 0).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_low_sample_rates() {
+        let style = GlyphStyle {
+            sample_rate: 2,
+            ..Default::default()
+        };
+        assert_eq!(
+            style.validate().unwrap_err().to_string(),
+            "Ta needs a sample rate of at least 3, got 2"
+        );
+        assert!(GlyphStyle::default().validate().is_ok());
+        // renderers raise the rate instead of failing
+        assert_eq!(style.sample_rate(Diacritic::Ta), 3);
+        assert_eq!(style.sample_rate(Diacritic::Wu), 2);
+        let ta = style.numeral_mesh(Numeral::new(0));
+        assert_eq!(ta.vertices.len(), 40 + Diacritic::Ta.vertex_count(3));
+        let raised = GlyphStyle {
+            sample_rate: 3,
+            ..Default::default()
+        };
+        assert_eq!(ta, raised.numeral_mesh(Numeral::new(0)));
+        assert_eq!(
+            ta.indices,
+            unit_numeral::numeral_indices(Numeral::new(0), 2)
+        );
+        let zero = GlyphStyle {
+            sample_rate: 0,
+            ..Default::default()
+        };
+        for u in Set::new().values() {
+            let bmp = rasterize_numeral(u, &zero, &RasterOptions::default());
+            assert!(bmp.width > 0);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let italic = GlyphStyle::italic();
+        let json = serde_json::to_string(&italic).unwrap();
+        assert!(json.contains("\"slant\":0.25"));
+        assert_eq!(serde_json::from_str::<GlyphStyle>(&json).unwrap(), italic);
+        // missing fields fall back to the regular preset
+        let partial: GlyphStyle = serde_json::from_str("{\"weight\":0.2}").unwrap();
+        assert_eq!(
+            partial,
+            GlyphStyle {
+                weight: 0.2,
+                ..GlyphStyle::regular()
+            }
+        );
+    }
+}
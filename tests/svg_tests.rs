@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod svg_tests {
+    use hyxos_numerals::svg::*;
+    use hyxos_numerals::*;
+
+    #[test]
+    fn test_numeral_svg_document() {
+        let style = SvgStyle::default();
+        let doc = numeral_svg(Numeral::new(26), &style);
+        assert!(doc.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(doc.trim_end().ends_with("</svg>"));
+        assert!(doc.contains("data-numeral=\"r2\""));
+        assert!(doc.contains("fill=\"black\""));
+        assert!(!doc.contains("stroke="));
+    }
+
+    #[test]
+    fn test_numeral_group_styles() {
+        let style = SvgStyle {
+            stroke: Some("white".to_string()),
+            tier_colors: Some(default_tier_colors()),
+            ..Default::default()
+        };
+        for u in Set::new().values() {
+            let g = numeral_group(u, &style);
+            assert!(g.starts_with("<g"));
+            assert!(!g.contains("<svg"));
+            assert!(g.contains("stroke=\"white\""));
+            assert!(g.contains(&format!("fill=\"{}\"", u.color())));
+            // every triangle becomes one closed subpath
+            assert!(g.matches('Z').count() > 10);
+        }
+    }
+
+    #[test]
+    fn test_flip_changes_geometry() {
+        let mut style = SvgStyle::default();
+        let upright = numeral_group(Numeral::new(40), &style);
+        style.glyph.flip = true;
+        let flipped = numeral_group(Numeral::new(40), &style);
+        assert_ne!(upright, flipped);
+    }
+
+    #[test]
+    fn test_colors_are_escaped() {
+        let style = SvgStyle {
+            fill: "a\"b&c<d".to_string(),
+            stroke: Some("\"/><script/>".to_string()),
+            ..Default::default()
+        };
+        let g = numeral_group(Numeral::new(7), &style);
+        assert!(g.contains("fill=\"a&quot;b&amp;c&lt;d\""));
+        assert!(g.contains("stroke=\"&quot;/>&lt;script/>\""));
+        assert!(!g.contains("<script"));
+    }
+}
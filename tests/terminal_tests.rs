@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod terminal_tests {
+    use hyxos_numerals::pick::tight_bounds;
+    use hyxos_numerals::raster::coverage;
+    use hyxos_numerals::style::GlyphStyle;
+    use hyxos_numerals::terminal::*;
+    use hyxos_numerals::typeset::*;
+    use hyxos_numerals::*;
+
+    fn strip_ansi(s: &str) -> String {
+        let mut out = String::new();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                for c in chars.by_ref() {
+                    if c == 'm' {
+                        break;
+                    }
+                }
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_braille_matches_coverage() {
+        let style = GlyphStyle::default();
+        let opts = TerminalOptions::default();
+        for n in [0, 13, 27, 59] {
+            let u = Numeral::new(n);
+            let art = numeral_terminal(u, &style, &opts);
+            assert!(art
+                .chars()
+                .all(|c| c == '\n' || c == ' ' || ('\u{2801}'..='\u{28ff}').contains(&c)));
+            let mesh = style.numeral_mesh(u);
+            let [x0, _, _, y1] = tight_bounds(&mesh).unwrap();
+            let width = 2 * art.lines().next().unwrap().chars().count();
+            let height = 4 * art.lines().count();
+            let cov = coverage(&mesh, width, height, [x0, y1], opts.scale, 4);
+            let mut lit = 0;
+            for (row, line) in art.lines().enumerate() {
+                for (col, c) in line.chars().enumerate() {
+                    let bits = if c == ' ' { 0 } else { c as u32 - 0x2800 };
+                    let dots = [
+                        (0, 0),
+                        (0, 1),
+                        (0, 2),
+                        (1, 0),
+                        (1, 1),
+                        (1, 2),
+                        (0, 3),
+                        (1, 3),
+                    ];
+                    for (i, (dx, dy)) in dots.iter().enumerate() {
+                        let p = (4 * row + dy) * width + 2 * col + dx;
+                        assert_eq!(bits & (1 << i) != 0, cov[p] >= opts.threshold, "{}", n);
+                        lit += (bits & (1 << i) != 0) as usize;
+                    }
+                }
+            }
+            assert!(lit > 0);
+        }
+    }
+
+    #[test]
+    fn test_half_blocks() {
+        let opts = TerminalOptions {
+            cells: Cells::HalfBlock,
+            ..Default::default()
+        };
+        let art = numeral_terminal(Numeral::new(27), &GlyphStyle::default(), &opts);
+        assert!(art.chars().all(|c| " ▀▄█\n".contains(c)));
+        assert!(art.contains('█'));
+        // half blocks take twice the lines braille does, less any rounding
+        // up to whole braille cells
+        let braille = numeral_terminal(
+            Numeral::new(27),
+            &GlyphStyle::default(),
+            &TerminalOptions::default(),
+        );
+        assert_eq!(art.lines().count().div_ceil(2), braille.lines().count());
+        assert_eq!(
+            art.lines().next().unwrap().chars().count(),
+            2 * braille.lines().next().unwrap().chars().count()
+        );
+    }
+
+    #[test]
+    fn test_colors() {
+        let style = GlyphStyle::default();
+        let colored = TerminalOptions {
+            colors: true,
+            ..Default::default()
+        };
+        for (n, code) in [(0, 33), (12, 34), (24, 31), (36, 35), (48, 32)] {
+            let u = Numeral::new(n);
+            assert_eq!(ansi_color(u.color()), Some(code));
+            let art = numeral_terminal(u, &style, &colored);
+            assert!(art.contains(&format!("\x1b[{}m", code)));
+            assert!(art.contains("\x1b[0m"));
+            assert_eq!(
+                strip_ansi(&art),
+                numeral_terminal(u, &style, &TerminalOptions::default())
+            );
+        }
+        let plain = numeral_terminal(Numeral::new(5), &style, &TerminalOptions::default());
+        assert!(!plain.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_uint_line() {
+        let style = GlyphStyle::default();
+        let layout_opts = LayoutOptions::default();
+        let opts = TerminalOptions {
+            colors: true,
+            ..Default::default()
+        };
+        // yellow, red and green digits
+        let u = Uint::new(2 * 3600 + 26 * 60 + 59);
+        let art = uint_terminal(&u, &layout_opts, &style, &opts);
+        for code in [33, 31, 32] {
+            assert!(art.contains(&format!("\x1b[{}m", code)));
+        }
+        let plain = strip_ansi(&art);
+        let width = plain.lines().next().unwrap().chars().count();
+        assert!(plain.lines().all(|l| l.chars().count() == width));
+        // wider than any single digit
+        let single = numeral_terminal(Numeral::new(59), &style, &TerminalOptions::default());
+        assert!(width > 2 * single.lines().next().unwrap().chars().count());
+    }
+
+    #[test]
+    fn test_set_grid() {
+        let style = GlyphStyle::default();
+        let opts = TerminalOptions {
+            colors: true,
+            ..Default::default()
+        };
+        let art = set_terminal(&style, &opts);
+        for code in [31, 32, 33, 34, 35] {
+            assert!(art.contains(&format!("\x1b[{}m", code)));
+        }
+        let plain = strip_ansi(&art);
+        let lines: Vec<&str> = plain.lines().collect();
+        let width = lines[0].chars().count();
+        assert!(lines.iter().all(|l| l.chars().count() == width));
+        // each tier sits in its own band of lines, painted only its color
+        let bands: Vec<Vec<u8>> = art
+            .lines()
+            .map(|l| {
+                let mut codes: Vec<u8> = l
+                    .split("\x1b[")
+                    .skip(1)
+                    .filter_map(|s| s.split('m').next()?.parse().ok())
+                    .filter(|c| (31..=35).contains(c))
+                    .collect();
+                codes.dedup();
+                codes
+            })
+            .filter(|c| !c.is_empty())
+            .collect();
+        assert!(bands.iter().all(|c| c.len() == 1));
+        let mut order: Vec<u8> = bands.iter().map(|c| c[0]).collect();
+        order.dedup();
+        assert_eq!(order, vec![33, 34, 31, 35, 32]);
+        // twelve columns of glyphs across
+        let mut blank = vec![true; width];
+        for l in &lines {
+            for (i, c) in l.chars().enumerate() {
+                blank[i] &= c == ' ';
+            }
+        }
+        let runs = blank.windows(2).filter(|w| w[0] && !w[1]).count() + !blank[0] as usize;
+        assert_eq!(runs, 12);
+    }
+}
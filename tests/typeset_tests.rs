@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod typeset_tests {
+    use hyxos_numerals::style::GlyphStyle;
+    use hyxos_numerals::svg::SvgStyle;
+    use hyxos_numerals::typeset::*;
+    use hyxos_numerals::*;
+    use std::f32::consts::PI;
+
+    #[test]
+    fn test_layout_advances() {
+        let u = Uint::new(3661);
+        let opts = LayoutOptions::default();
+        let l = layout(&u, &opts, &GlyphStyle::with(PI / 3.0, 0.1, false, 16));
+        assert_eq!(l.glyphs.len(), 3);
+        assert_eq!(l.lines, 1);
+        assert!(l.separators.is_empty());
+        assert!(l.glyphs.windows(2).all(|w| w[0].origin[0] < w[1].origin[0]));
+        // every glyph body rests on the baseline
+        assert!(l
+            .glyphs
+            .iter()
+            .all(|p| (p.origin[1] - 0.5 * (PI / 3.0).sin()).abs() < 1e-6));
+
+        let mut kerned = LayoutOptions::default();
+        kerned
+            .kerning
+            .insert((Numeral::new(1), Numeral::new(1)), 0.5);
+        let k = layout(&u, &kerned, &GlyphStyle::with(PI / 3.0, 0.1, false, 16));
+        assert!((k.glyphs[2].origin[0] - l.glyphs[2].origin[0] - 1.0).abs() < 1e-5);
+        assert!((k.glyphs[1].origin[0] - l.glyphs[1].origin[0] - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_monospace_advance() {
+        let style = GlyphStyle::default();
+        let opts = LayoutOptions::default();
+        let [x0, x1] = digit_span(&style);
+        // the cell holds every numeral whole
+        for n in 0..60 {
+            let [lx0, _, lx1, _] = style.numeral_mesh(Numeral::new(n)).bounds2d();
+            assert!(lx0 >= x0 && lx1 <= x1);
+        }
+        // the stem of 1 gets the same advance as a full glyph
+        let l = layout(&Uint::new(60 + 1), &opts, &style);
+        assert_eq!(l.glyphs[0].advance, l.glyphs[1].advance);
+        assert!((l.glyphs[0].advance - (x1 - x0) - opts.spacing).abs() < 1e-6);
+        let ink = pick::numeral_bounds(Numeral::new(1), &style);
+        assert!(ink[2] - ink[0] < (x1 - x0) / 2.0);
+    }
+
+    #[test]
+    fn test_layout_wraps_and_separates() {
+        let u = Uint::new(777600001);
+        let opts = LayoutOptions {
+            separator: Some(Separator::Colon),
+            max_width: Some(4.0),
+            ..Default::default()
+        };
+        let l = layout(&u, &opts, &GlyphStyle::with(PI / 3.0, 0.1, false, 16));
+        assert_eq!(l.glyphs.len(), 6);
+        assert_eq!(l.lines, 2);
+        assert_eq!(l.separators.len(), 4);
+        assert!(l.width <= 4.0);
+        assert!(l.glyphs[3].origin[1] < l.glyphs[2].origin[1]);
+        assert_eq!(l.glyphs[3].line, 1);
+
+        let mesh = typeset_mesh(&u, &opts, &GlyphStyle::with(PI / 3.0, 0.1, false, 16));
+        let idx = &mesh.indices;
+        assert!(idx.iter().all(|&i| (i as usize) < mesh.vertices.len()));
+        // each colon is two squares of two triangles
+        let digits: usize = u
+            .values()
+            .iter()
+            .map(|n| hyxos_numerals::unit_numeral::numeral_indices(*n, 16).len())
+            .sum();
+        assert_eq!(idx.len(), digits + 4 * 2 * 6);
+    }
+
+    #[test]
+    fn test_typeset_svg() {
+        let u = Uint::new(7200);
+        let opts = LayoutOptions {
+            separator: Some(Separator::Dot),
+            ..Default::default()
+        };
+        let doc = typeset_svg(&u, &opts, &SvgStyle::default());
+        assert!(doc.starts_with("<svg"));
+        assert_eq!(doc.matches("data-numeral").count(), 3);
+        assert_eq!(doc.matches("translate(").count(), 3);
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod wu_xing_tests {
+    use hyxos_numerals::wu_xing::*;
+    use hyxos_numerals::*;
+
+    #[test]
+    fn test_relations() {
+        let earth = Numeral::new(0);
+        let water = Numeral::new(12);
+        let fire = Numeral::new(24);
+        let metal = Numeral::new(36);
+        let wood = Numeral::new(48);
+
+        assert_eq!(relation(wood, fire), Relation::Generates);
+        assert_eq!(relation(fire, earth), Relation::Generates);
+        assert_eq!(relation(earth, fire), Relation::GeneratedBy);
+        assert_eq!(relation(water, fire), Relation::Overcomes);
+        assert_eq!(relation(earth, wood), Relation::OvercomeBy);
+        assert_eq!(relation(metal, Numeral::new(40)), Relation::Same);
+        assert_eq!(relation(metal, wood).name(), "overcomes");
+        assert_eq!(relation(metal, wood).name_cn(), "我克");
+    }
+
+    #[test]
+    fn test_relations_are_antisymmetric() {
+        for a in Set::new().values() {
+            for b in Set::new().values() {
+                let inverse = match relation(a, b) {
+                    Relation::Same => Relation::Same,
+                    Relation::Generates => Relation::GeneratedBy,
+                    Relation::GeneratedBy => Relation::Generates,
+                    Relation::Overcomes => Relation::OvercomeBy,
+                    Relation::OvercomeBy => Relation::Overcomes,
+                };
+                assert_eq!(relation(b, a), inverse);
+            }
+        }
+    }
+
+    #[test]
+    fn test_element_helpers() {
+        let water = Numeral::new(21);
+        assert_eq!(produces(water), "wood");
+        assert_eq!(produced_by(water), "metal");
+        assert_eq!(controls(water), "fire");
+        assert_eq!(controlled_by(water), "earth");
+        assert_eq!(element_cn(producing_index(water)), "金");
+        assert_eq!(diacritic_index(controlled_index(water)), 2);
+        for g in 0..5 {
+            let u = Numeral::new(diacritic_index(g) * 12);
+            assert_eq!(u.generating_index(), g);
+            assert_eq!(element(g), u.element());
+        }
+    }
+}